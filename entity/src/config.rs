@@ -1,19 +1,43 @@
-use multiversx_sc::api::ED25519_SIGNATURE_BYTE_LEN;
+use multiversx_sc::api::{StorageMapperApi, ED25519_SIGNATURE_BYTE_LEN};
+use multiversx_sc::codec::{DecodeErrorHandler, NestedDecodeInput, TopDecodeInput};
 
-use crate::{governance::proposal::{Proposal, ProposalDetails, ProposalStatus}, permission::RoleName};
+use crate::{governance::proposal::{Proposal, ProposalDetails, ProposalStatus, VoteReceipt}, permission::{QuorumMode, RoleName}, plug::{PlugSettings, PlugWeightRequest}};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 pub const VOTING_PERIOD_MINUTES_DEFAULT: usize = 4320; // 3 days
 pub const VOTING_PERIOD_MINUTES_MAX: usize = 20_160; // 14 days
+pub const EXECUTION_DELAY_MINUTES_MAX: usize = 20_160; // 14 days
+pub const EXECUTION_WINDOW_MINUTES_MAX: usize = 43_200; // 30 days
+pub const UNBONDING_PERIOD_MINUTES_DEFAULT: usize = 10_080; // 7 days
+pub const UNBONDING_PERIOD_MINUTES_MAX: usize = 43_200; // 30 days
+pub const ESCROW_LOCK_MINUTES_MAX: usize = 1_051_200; // 2 years
 pub const MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT: u64 = 1;
 pub const QUORUM_DEFAULT: u64 = 1;
+pub const APPROVAL_THRESHOLD_DEFAULT: u64 = 5_000; // 50%
+pub const APPROVAL_THRESHOLD_MAX: u64 = 10_000; // 100%
+pub const ROLE_HIERARCHY_DEPTH_MAX: usize = 5;
+pub const QUORUM_PERCENTAGE_DEFAULT: u64 = 500; // 5%
+pub const QUORUM_PERCENTAGE_MAX: u64 = 10_000; // 100%
+pub const GOV_TOKEN_MULTIPLIER_BASE: u64 = 10_000; // 1x
+pub const GOV_TOKEN_MULTIPLIER_MAX: u64 = 1_000_000; // 100x
+pub const PLUG_MULTIPLIER_BASE: u64 = 10_000; // 1x
+pub const PLUG_MULTIPLIER_MAX: u64 = 1_000_000; // 100x
+pub const CONVICTION_PERIOD_SECONDS: u64 = 3_600; // 1 hour
+pub const CONVICTION_PERIODS_MAX: u64 = 720; // 30 days, after which conviction has practically converged
+pub const CONVICTION_DECAY_DEFAULT: u64 = 9_000; // 90% kept per period
+pub const CONVICTION_MAX_RATIO_DEFAULT: u64 = 2_000; // 20% of the treasury
+pub const CONVICTION_WEIGHT_DEFAULT: u64 = 100; // 1% of the circulating supply
+pub const CONVICTION_PARAMETER_MAX: u64 = 10_000; // 100%
+pub const CONVICTION_EXECUTION_DELAY_MINUTES_MIN: usize = 1_440; // 1 day for guardians to veto a payout
 
 pub const POLL_MAX_OPTIONS: u8 = 20;
 
 pub const GAS_LIMIT_SET_TOKEN_ROLES: u64 = 60_000_000;
 pub const GAS_LIMIT_WITHDRAW: u64 = 1_000_000;
+pub const GAS_LIMIT_PLUG_WEIGHT: u64 = 10_000_000;
+pub const GAS_LIMIT_PLUG_WEIGHT_CALLBACK: u64 = 20_000_000;
 
 pub const TOKEN_MAX_DECIMALS: u8 = 18;
 
@@ -32,21 +56,117 @@ pub struct LockedVote<M: ManagedTypeApi> {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(TopEncode, NestedEncode, NestedDecode)]
 pub struct ProposalRole<M: ManagedTypeApi> {
     pub votes_for: BigUint<M>,
     pub votes_against: BigUint<M>,
+    pub votes_abstain: BigUint<M>,
+}
+
+// tallies stored before abstain votes existed end after the against votes
+impl<M: ManagedTypeApi> TopDecode for ProposalRole<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut input = input.into_nested_buffer();
+
+        let role = Self {
+            votes_for: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            votes_against: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            votes_abstain: dep_decode_trailing_or_default(&mut input, h, BigUint::zero)?,
+        };
+
+        require_input_depleted(&input, h)?;
+
+        Ok(role)
+    }
 }
 
 impl<M: ManagedTypeApi> Default for ProposalRole<M> {
     fn default() -> Self {
         Self {
-            votes_for: 0,
-            votes_against: 0,
+            votes_for: BigUint::zero(),
+            votes_against: BigUint::zero(),
+            votes_abstain: BigUint::zero(),
         }
     }
 }
 
+/// Decodes a field that was appended to a stored struct, falling back to a default for values encoded before it existed.
+pub fn dep_decode_trailing_or_default<T, I, H, F>(input: &mut I, h: H, default: F) -> Result<T, H::HandledErr>
+where
+    T: NestedDecode,
+    I: NestedDecodeInput,
+    H: DecodeErrorHandler,
+    F: FnOnce() -> T,
+{
+    if input.is_depleted() {
+        return Ok(default());
+    }
+
+    T::dep_decode_or_handle_err(input, h)
+}
+
+pub fn require_input_depleted<I, H>(input: &I, h: H) -> Result<(), H::HandledErr>
+where
+    I: NestedDecodeInput,
+    H: DecodeErrorHandler,
+{
+    if !input.is_depleted() {
+        return Err(h.handle_error(DecodeError::INPUT_TOO_LONG));
+    }
+
+    Ok(())
+}
+
+/// A value recorded at a point in time, evaluated right before the start of proposals.
+pub trait Checkpoint {
+    fn timestamp(&self) -> Timestamp;
+}
+
+/// Appends a checkpoint, or replaces the last one if it was recorded at the same timestamp.
+pub fn write_checkpoint<SA, T>(checkpoints: &mut VecMapper<SA, T>, checkpoint: &T)
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Checkpoint + 'static,
+{
+    let last = checkpoints.len();
+
+    if last > 0 && checkpoints.get(last).timestamp() == checkpoint.timestamp() {
+        checkpoints.set(last, checkpoint);
+    } else {
+        checkpoints.push(checkpoint);
+    }
+}
+
+/// Returns the last checkpoint recorded before the given timestamp, if any.
+/// Checkpoints recorded at or after the timestamp are not considered.
+pub fn find_checkpoint_before<SA, T>(checkpoints: &VecMapper<SA, T>, timestamp: Timestamp) -> Option<T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + Checkpoint + 'static,
+{
+    let (mut low, mut high) = (1, checkpoints.len() + 1);
+
+    // finds the first checkpoint at or after the timestamp
+    while low < high {
+        let mid = (low + high) / 2;
+        if checkpoints.get(mid).timestamp() < timestamp {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 1 {
+        return None;
+    }
+
+    Some(checkpoints.get(low - 1))
+}
+
 #[multiversx_sc::module]
 pub trait ConfigModule {
     fn require_caller_self(&self) {
@@ -62,13 +182,21 @@ pub trait ConfigModule {
     }
 
     fn require_weighted_gov_method(&self) {
-        let has_gov_token = !self.gov_token().is_empty();
+        let has_gov_token = !self.gov_token().is_empty() || !self.gov_token_multipliers().is_empty();
         let has_plug = self.is_plugged();
 
         require!(has_gov_token || has_plug, "gov token or plug must be set");
     }
 
+    /// Requires all payments to be made with the governance token or any additionally registered governance token.
     fn require_payments_with_gov_token(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
+        for payment in payments.into_iter() {
+            require!(self.is_weighted_gov_token(&payment.token_identifier), "invalid payment token");
+        }
+    }
+
+    /// Requires all payments to be made with the governance token itself, excluding additionally registered ones.
+    fn require_payments_with_primary_gov_token(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
         let gov_token_id = self.gov_token().get();
 
         for payment in payments.into_iter() {
@@ -76,6 +204,29 @@ pub trait ConfigModule {
         }
     }
 
+    fn is_weighted_gov_token(&self, token: &TokenIdentifier) -> bool {
+        let is_primary = !self.gov_token().is_empty() && &self.gov_token().get() == token;
+
+        is_primary || self.gov_token_multipliers().contains_key(token)
+    }
+
+    /// Returns the vote weight of a governance token payment.
+    /// The governance token counts 1x, additionally registered governance tokens count with their multiplier
+    /// and any other token, like a deregistered one, counts nothing.
+    fn get_gov_token_payment_weight(&self, payment: &EsdtTokenPayment<Self::Api>) -> BigUint {
+        if let Some(multiplier) = self.gov_token_multipliers().get(&payment.token_identifier) {
+            return &payment.amount * multiplier / GOV_TOKEN_MULTIPLIER_BASE;
+        }
+
+        let is_primary = !self.gov_token().is_empty() && self.gov_token().get() == payment.token_identifier;
+
+        if is_primary {
+            payment.amount.clone()
+        } else {
+            BigUint::zero()
+        }
+    }
+
     fn require_tokens_available(&self, token: &TokenIdentifier, nonce: u64, amount: &BigUint) {
         let protected = self.guarded_vote_tokens(token, nonce).get();
         let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token.clone()), nonce);
@@ -93,7 +244,7 @@ pub trait ConfigModule {
         let signable_hashed = self.crypto().keccak256(signable);
 
         self.crypto()
-            .verify_ed25519(trusted_host.as_managed_buffer(), signable_hashed.as_managed_buffer(), &signature.as_managed_buffer());
+            .verify_ed25519(trusted_host.as_managed_buffer(), signable_hashed.as_managed_buffer(), signature.as_managed_buffer());
     }
 
     fn require_vote_tokens_allowed(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
@@ -108,7 +259,7 @@ pub trait ConfigModule {
     }
 
     fn is_plugged(&self) -> bool {
-        !self.plug_contract().is_empty()
+        !self.plugs().is_empty()
     }
 
     fn get_gov_token_option(&self) -> Option<TokenIdentifier> {
@@ -119,6 +270,11 @@ pub trait ConfigModule {
         Some(self.gov_token().get())
     }
 
+    /// Returns the decimals of the governance token, which are the common precision of all vote weights.
+    fn get_gov_token_decimals(&self) -> u8 {
+        self.gov_token_decimals().get().unwrap_or(TOKEN_MAX_DECIMALS)
+    }
+
     fn get_plug_option(&self) -> Option<ManagedAddress> {
         if self.plug_contract().is_empty() {
             return None;
@@ -137,6 +293,54 @@ pub trait ConfigModule {
         self.default_quorum().set(&quorum);
     }
 
+    fn try_change_default_quorum_mode(&self, quorum: &BigUint, mode: QuorumMode) {
+        self.require_valid_quorum_for_mode(quorum, mode);
+        self.default_quorum_mode().set(mode);
+    }
+
+    fn require_valid_quorum_for_mode(&self, quorum: &BigUint, mode: QuorumMode) {
+        if mode == QuorumMode::Percentage {
+            require!(quorum <= &BigUint::from(QUORUM_PERCENTAGE_MAX), "max quorum percentage exceeded");
+            require!(!self.quorum_fallback().is_empty(), "quorum fallback not set");
+        }
+    }
+
+    fn try_change_quorum_fallback(&self, quorum: BigUint) {
+        require!(quorum != 0, "invalid quorum");
+        self.quorum_fallback().set(&quorum);
+    }
+
+    /// Returns the vote weight of all governance tokens outside of the entity's treasury,
+    /// with additionally registered governance tokens counting with their multiplier.
+    /// Tokens locked in the contract for voting are considered circulating.
+    fn get_circulating_gov_token_supply(&self) -> BigUint {
+        let mut circulating = BigUint::zero();
+
+        if !self.gov_token().is_empty() {
+            circulating += self.get_circulating_token_supply(&self.gov_token().get(), self.gov_token_supply().get());
+        }
+
+        for (token, multiplier) in self.gov_token_multipliers().iter() {
+            let supply = self.additional_gov_token_supply(&token).get();
+            circulating += self.get_circulating_token_supply(&token, supply) * multiplier / GOV_TOKEN_MULTIPLIER_BASE;
+        }
+
+        circulating
+    }
+
+    /// Returns the amount of a token's supply outside of the entity's treasury.
+    fn get_circulating_token_supply(&self, token: &TokenIdentifier, supply: BigUint) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token.clone()), 0);
+        let guarded = self.guarded_vote_tokens(token, 0).get();
+        let treasury = if balance > guarded { balance - guarded } else { BigUint::zero() };
+
+        if treasury >= supply {
+            return BigUint::zero();
+        }
+
+        supply - treasury
+    }
+
     fn try_change_min_vote_weight(&self, vote_weight: BigUint) {
         require!(vote_weight != 0, "min vote weight can not be zero");
         self.min_vote_weight().set(&vote_weight);
@@ -150,7 +354,17 @@ pub trait ConfigModule {
     fn try_change_default_voting_period_minutes(&self, voting_period: usize) {
         require!(voting_period != 0, "voting period can not be zero");
         require!(voting_period <= VOTING_PERIOD_MINUTES_MAX, "max voting period exceeded");
-        self.default_voting_period_minutes().set(&voting_period);
+        self.default_voting_period_minutes().set(voting_period);
+    }
+
+    fn try_change_default_execution_delay_minutes(&self, execution_delay: usize) {
+        require!(execution_delay <= EXECUTION_DELAY_MINUTES_MAX, "max execution delay exceeded");
+        self.default_execution_delay_minutes().set(execution_delay);
+    }
+
+    fn try_change_default_execution_window_minutes(&self, execution_window: usize) {
+        require!(execution_window <= EXECUTION_WINDOW_MINUTES_MAX, "max execution window exceeded");
+        self.default_execution_window_minutes().set(execution_window);
     }
 
     #[storage_mapper("users")]
@@ -164,6 +378,21 @@ pub trait ConfigModule {
     #[storage_mapper("gov_token_id")]
     fn gov_token(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    /// Additional governance tokens with their weight multiplier in basis points, where 10_000 counts 1x.
+    #[storage_mapper("gov_token_multipliers")]
+    fn gov_token_multipliers(&self) -> MapMapper<TokenIdentifier, u64>;
+
+    /// The supply of additional governance tokens, tracked to evaluate percentage-based quorums.
+    #[storage_mapper("gov_token_supplies")]
+    fn additional_gov_token_supply(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("gov_token_decimals")]
+    fn gov_token_decimals(&self) -> SingleValueMapper<Option<u8>>;
+
+    #[view(getGovTokenSupply)]
+    #[storage_mapper("gov_token_supply")]
+    fn gov_token_supply(&self) -> SingleValueMapper<BigUint>;
+
     #[view(getGuardedVoteTokens)]
     #[storage_mapper("guarded_vote_tokens")]
     fn guarded_vote_tokens(&self, token_id: &TokenIdentifier, nonce: u64) -> SingleValueMapper<BigUint>;
@@ -178,9 +407,33 @@ pub trait ConfigModule {
     #[storage_mapper("proposal_details")]
     fn proposal_details(&self, id: ProposalId) -> SingleValueMapper<ProposalDetails<Self::Api>>;
 
+    /// Member amounts of the proposal's and proposer's roles, frozen at proposal creation for signer policies.
+    #[storage_mapper("proposal_role_member_amounts")]
+    fn proposal_role_member_amounts(&self, id: ProposalId) -> MapMapper<RoleName<Self::Api>, usize>;
+
     #[storage_mapper("proposal_role_info")]
     fn proposal_role_info(&self, id: ProposalId, role: &RoleName<Self::Api>) -> SingleValueMapper<ProposalRole<Self::Api>>;
 
+    /// Committed weight of the FOR and ABSTAIN votes for a role under a quadratic policy, before taking the square root.
+    #[storage_mapper("proposal_role_quorum_weight")]
+    fn proposal_role_quorum_weight(&self, id: ProposalId, role: &RoleName<Self::Api>) -> SingleValueMapper<BigUint>;
+
+    /// Circulating voting power recorded on the last vote of a proposal with a percentage quorum.
+    #[storage_mapper("proposal_voting_power")]
+    fn proposal_voting_power(&self, id: ProposalId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposal_succeeded_at")]
+    fn proposal_succeeded_at(&self, id: ProposalId) -> SingleValueMapper<Timestamp>;
+
+    #[storage_mapper("proposal_execution_limits")]
+    fn proposal_execution_limits(&self, id: ProposalId) -> SingleValueMapper<(u64, u64)>;
+
+    #[storage_mapper("proposal_code_hash")]
+    fn proposal_code_hash(&self, id: ProposalId) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("proposal_veto_reason")]
+    fn proposal_veto_reason(&self, id: ProposalId) -> SingleValueMapper<ManagedBuffer>;
+
     #[storage_mapper("proposal_results")]
     fn proposal_results(&self, proposal: ProposalId) -> SingleValueMapper<ProposalStatus>;
 
@@ -195,6 +448,16 @@ pub trait ConfigModule {
     #[storage_mapper("proposal_nft_votes")]
     fn proposal_nft_votes(&self, proposal: ProposalId) -> UnorderedSetMapper<u64>;
 
+    #[storage_mapper("vote_receipts")]
+    fn vote_receipts(&self, proposal: ProposalId, voter: UserId) -> SingleValueMapper<VoteReceipt<Self::Api>>;
+
+    #[storage_mapper("proposal_voters")]
+    fn proposal_voters(&self, proposal: ProposalId) -> UnorderedSetMapper<UserId>;
+
+    /// Voters who have used the weight of their own staked and escrowed governance tokens on a proposal.
+    #[storage_mapper("proposal_deposit_voters")]
+    fn proposal_deposit_voters(&self, proposal: ProposalId) -> UnorderedSetMapper<UserId>;
+
     #[storage_mapper("proposal_poll")]
     fn proposal_poll(&self, proposal: ProposalId, option: ProposalOptionId) -> SingleValueMapper<BigUint>;
 
@@ -232,6 +495,15 @@ pub trait ConfigModule {
     #[storage_mapper("default_quorum")]
     fn default_quorum(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getQuorumMode)]
+    #[storage_mapper("default_quorum_mode")]
+    fn default_quorum_mode(&self) -> SingleValueMapper<QuorumMode>;
+
+    /// Absolute quorum applied to percentage quorums while the circulating voting power is unknown.
+    #[view(getQuorumFallback)]
+    #[storage_mapper("quorum_fallback")]
+    fn quorum_fallback(&self) -> SingleValueMapper<BigUint>;
+
     #[view(getMinVoteWeight)]
     #[storage_mapper("min_vote_weight")]
     fn min_vote_weight(&self) -> SingleValueMapper<BigUint>;
@@ -244,16 +516,47 @@ pub trait ConfigModule {
     #[storage_mapper("default_voting_period_minutes")]
     fn default_voting_period_minutes(&self) -> SingleValueMapper<usize>;
 
+    #[view(getExecutionDelayMinutes)]
+    #[storage_mapper("default_execution_delay_minutes")]
+    fn default_execution_delay_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getExecutionWindowMinutes)]
+    #[storage_mapper("default_execution_window_minutes")]
+    fn default_execution_window_minutes(&self) -> SingleValueMapper<usize>;
+
     #[view(getRestrictedVoteNonces)]
     #[storage_mapper("restricted_vote_nonces")]
     fn restricted_vote_nonces(&self) -> UnorderedSetMapper<u64>;
 
+    #[storage_mapper("delegation:delegates")]
+    fn delegates(&self, delegator: UserId) -> SingleValueMapper<UserId>;
+
     #[storage_mapper("plug:contract")]
     fn plug_contract(&self) -> SingleValueMapper<ManagedAddress>;
 
     #[storage_mapper("plug:weight_decimals")]
     fn plug_weight_decimals(&self) -> SingleValueMapper<u8>;
 
+    #[storage_mapper("plug:plugs")]
+    fn plugs(&self) -> MapMapper<ManagedAddress, PlugSettings>;
+
+    #[storage_mapper("plug:reports_total_weight")]
+    fn plug_reports_total_weight(&self, plug: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Voters who used the weight of the main plug before multiple plugs were supported.
     #[storage_mapper("plug:votes")]
-    fn plug_votes(&self, proposal: ProposalId) -> UnorderedSetMapper<UserId>;
+    fn legacy_plug_votes(&self, proposal: ProposalId) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("plug:plug_votes")]
+    fn plug_votes(&self, proposal: ProposalId, plug: &ManagedAddress) -> UnorderedSetMapper<UserId>;
+
+    /// The plugs that counted toward votes on a proposal, including plugs removed since.
+    #[storage_mapper("plug:proposal_plugs")]
+    fn proposal_plugs(&self, proposal: ProposalId) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("plug:next_request_id")]
+    fn next_plug_request_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("plug:requests")]
+    fn plug_requests(&self, request: u64) -> SingleValueMapper<PlugWeightRequest<Self::Api>>;
 }
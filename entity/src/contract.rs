@@ -0,0 +1,350 @@
+multiversx_sc::imports!();
+
+use crate::config::{self, ProposalId, UserId};
+use crate::governance::{delegation, escrow, events, staking};
+use crate::governance::proposal::{self, ProposalStatus};
+use crate::permission::{self, PermissionName, RoleName, ROLE_BUILTIN_DEVELOPER};
+use crate::plug;
+
+#[multiversx_sc::module]
+pub trait ContractModule:
+    config::ConfigModule
+    + permission::PermissionModule
+    + plug::PlugModule
+    + events::GovEventsModule
+    + staking::StakingModule
+    + escrow::EscrowModule
+    + delegation::DelegationModule
+    + proposal::ProposalModule
+{
+    /// Lock the contract stage of the given address.
+    /// A locked stage can not be overwritten until it is unlocked or activated.
+    /// Can only be called by the contract itself.
+    #[endpoint(lockContractStage)]
+    fn lock_contract_stage_endpoint(&self, address: ManagedAddress) {
+        self.require_caller_self();
+        require!(!self.stage(&address).is_empty(), "contract stage is empty");
+
+        self.stage_lock(&address).set(true);
+    }
+
+    /// Unlock and clear the contract stage of the given address.
+    /// Can only be called by the contract itself.
+    #[endpoint(unlockContractStage)]
+    fn unlock_contract_stage_endpoint(&self, address: ManagedAddress) {
+        self.require_caller_self();
+        self.clear_contract_stage(&address);
+    }
+
+    /// Stage the code of a contract to be activated later through a proposal.
+    /// Staging for the zero address prepares the deployment of a new child contract instead of an upgrade.
+    /// Can only be called by users with the developer role.
+    #[endpoint(stageContract)]
+    fn stage_contract_endpoint(&self, address: ManagedAddress, code: ManagedBuffer) {
+        self.require_caller_has_developer_role();
+        self.stage_contract(&address, code);
+    }
+
+    /// Stage the code of a contract and create a proposal to activate it in one go.
+    /// The stage gets locked until the proposal is either executed, defeated or replaced.
+    /// The trusted host signature must additionally cover the keccak256 hash of the code.
+    /// A still active proposal of a previous stage for the same address will be canceled.
+    /// The proposal is created for all roles of the caller that have a policy for one of the given permissions.
+    /// Can only be called by users with the developer role.
+    /// Returns the id of the created proposal.
+    #[endpoint(stageContractAndPropose)]
+    fn stage_contract_and_propose_endpoint(
+        &self,
+        address: ManagedAddress,
+        code: ManagedBuffer,
+        trusted_host_id: ManagedBuffer,
+        content_hash: ManagedBuffer,
+        content_sig: ManagedBuffer,
+        actions_hash: ManagedBuffer,
+        permissions: MultiValueManagedVec<PermissionName<Self::Api>>,
+    ) -> ProposalId {
+        self.require_caller_has_developer_role();
+        self.release_contract_stage(&address);
+        self.stage_contract(&address, code);
+
+        let caller = self.blockchain().get_caller();
+        let proposer = self.users().get_or_create_user(&caller);
+        let code_hash = self.stage_code_hash(&address).get();
+        let roles = self.get_user_roles_for_permissions(proposer, &permissions.into_vec());
+        require!(!roles.is_empty(), "no role with policy for permissions");
+
+        let proposal = self.create_proposal(
+            proposer,
+            trusted_host_id,
+            content_hash,
+            content_sig,
+            actions_hash,
+            0,
+            0,
+            BigUint::zero(),
+            roles,
+            code_hash,
+        );
+
+        self.stage_lock(&address).set(true);
+        self.stage_current_proposal(&address).set(proposal.id);
+
+        proposal.id
+    }
+
+    /// Activate the staged code by upgrading the contract at the given address.
+    /// Code staged for the zero address is deployed as a new child contract instead.
+    /// Requires the stage to be locked by a proposal.
+    /// Fails if the staged code does not match the code hash approved with the proposal.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - unique_id: an id that prevents the same activation from being executed twice
+    ///     - address: the contract to be upgraded with the staged code, or the zero address to deploy it
+    ///     - code_hash: the keccak256 hash of the staged code
+    ///     - code_metadata: the code metadata of the upgraded or deployed contract
+    ///     - args: arguments passed to the upgrade or init function of the contract
+    #[endpoint(activateContract)]
+    fn activate_contract_endpoint(
+        &self,
+        unique_id: ManagedBuffer,
+        address: ManagedAddress,
+        code_hash: ManagedBuffer,
+        code_metadata: CodeMetadata,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_caller_self();
+        require!(!self.stage(&address).is_empty(), "contract not staged");
+        require!(!self.unique_ids().contains(&unique_id), "unique id already exists");
+        require!(self.is_stage_locked(&address), "contract stage must be locked");
+
+        let code = self.stage(&address).get();
+        require!(self.stage_code_hash(&address).get() == code_hash, "code hash mismatch");
+        require!(self.calculate_code_hash(&code) == code_hash, "staged code has been corrupted");
+
+        self.unique_ids().insert(unique_id);
+        self.clear_contract_stage(&address);
+
+        if address.is_zero() {
+            self.deploy_child_contract(code, &code_hash, code_metadata, args);
+            return;
+        }
+
+        if self.child_contracts().contains(&address) {
+            self.child_contract_code_hash(&address).set(&code_hash);
+        }
+
+        self.tx()
+            .to(address)
+            .raw_upgrade()
+            .code(code)
+            .code_metadata(code_metadata)
+            .arguments_raw(args.to_arg_buffer())
+            .upgrade_async_call_and_exit();
+    }
+
+    /// Stage code to deploy new child contracts from.
+    /// Staged deployment code is identified by its keccak256 hash and can be deployed multiple times.
+    /// Can only be called by users with the developer role.
+    /// Returns the code hash.
+    #[endpoint(stageDeployment)]
+    fn stage_deployment_endpoint(&self, code: ManagedBuffer) -> ManagedBuffer {
+        self.require_caller_has_developer_role();
+        require!(!code.is_empty(), "code must not be empty");
+
+        let code_hash = self.calculate_code_hash(&code);
+        self.deploy_stage(&code_hash).set(code);
+
+        code_hash
+    }
+
+    /// Remove staged deployment code.
+    /// Can only be called by the contract itself.
+    #[endpoint(unstageDeployment)]
+    fn unstage_deployment_endpoint(&self, code_hash: ManagedBuffer) {
+        self.require_caller_self();
+        require!(!self.deploy_stage(&code_hash).is_empty(), "deployment not staged");
+
+        self.deploy_stage(&code_hash).clear();
+    }
+
+    /// Deploy a new child contract owned by the entity from staged deployment code.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - unique_id: an id that prevents the same deployment from being executed twice
+    ///     - code_hash: the keccak256 hash of the staged deployment code
+    ///     - code_metadata: the code metadata of the deployed contract
+    ///     - args: arguments passed to the init function of the contract
+    /// Returns the address of the deployed contract.
+    #[endpoint(deployContract)]
+    fn deploy_contract_endpoint(
+        &self,
+        unique_id: ManagedBuffer,
+        code_hash: ManagedBuffer,
+        code_metadata: CodeMetadata,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) -> ManagedAddress {
+        self.require_caller_self();
+        require!(!self.deploy_stage(&code_hash).is_empty(), "deployment not staged");
+        require!(!self.unique_ids().contains(&unique_id), "unique id already exists");
+
+        let code = self.deploy_stage(&code_hash).get();
+
+        self.unique_ids().insert(unique_id);
+
+        self.deploy_child_contract(code, &code_hash, code_metadata, args)
+    }
+
+    #[view(getContractStage)]
+    fn get_contract_stage_view(&self, address: ManagedAddress) -> MultiValue2<ManagedBuffer, bool> {
+        let code = self.stage(&address).get();
+        let locked = self.is_stage_locked(&address);
+
+        (code, locked).into()
+    }
+
+    /// Returns the keccak256 hash of the staged code and the address of the developer who staged it.
+    #[view(getContractStageHash)]
+    fn get_contract_stage_hash_view(&self, address: ManagedAddress) -> MultiValue2<ManagedBuffer, ManagedAddress> {
+        let code_hash = self.stage_code_hash(&address).get();
+        let stager = self.stage_stager(&address).get();
+
+        (code_hash, stager).into()
+    }
+
+    /// Returns all contracts deployed by the entity along with the hash of their code.
+    /// The code hash gets updated when a child contract is upgraded through `activateContract`.
+    #[view(getChildContracts)]
+    fn get_child_contracts_view(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>> {
+        let mut children = MultiValueEncoded::new();
+
+        for address in self.child_contracts().iter() {
+            let code_hash = self.child_contract_code_hash(&address).get();
+            children.push((address, code_hash).into());
+        }
+
+        children
+    }
+
+    fn stage_contract(&self, address: &ManagedAddress, code: ManagedBuffer) {
+        require!(!self.is_stage_locked(address), "contract stage is locked");
+        require!(!code.is_empty(), "code must not be empty");
+        require!(address.is_zero() || self.blockchain().is_smart_contract(address), "address must be contract");
+        require!(address != &self.blockchain().get_sc_address(), "address must not be self");
+
+        let code_hash = self.calculate_code_hash(&code);
+        let stager = self.blockchain().get_caller();
+
+        self.stage(address).set(code);
+        self.stage_code_hash(address).set(code_hash);
+        self.stage_stager(address).set(stager);
+    }
+
+    /// Frees the stage from the proposal currently referencing it.
+    /// Cancels the proposal if it is still active and fails if it already succeeded.
+    fn release_contract_stage(&self, address: &ManagedAddress) {
+        if self.stage_current_proposal(address).is_empty() {
+            return;
+        }
+
+        let proposal_id = self.stage_current_proposal(address).get();
+
+        if !self.proposals(proposal_id).is_empty() {
+            let proposal = self.proposals(proposal_id).get();
+
+            match self.get_proposal_status(&proposal) {
+                ProposalStatus::Active => self.cancel_proposal(proposal),
+                ProposalStatus::Succeeded | ProposalStatus::Queued => sc_panic!("contract stage is locked"),
+                _ => {}
+            }
+        }
+
+        self.clear_contract_stage(address);
+    }
+
+    fn deploy_child_contract(&self, code: ManagedBuffer, code_hash: &ManagedBuffer, code_metadata: CodeMetadata, args: MultiValueEncoded<ManagedBuffer>) -> ManagedAddress {
+        let address = self
+            .tx()
+            .raw_deploy()
+            .code(code)
+            .code_metadata(code_metadata)
+            .arguments_raw(args.to_arg_buffer())
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
+
+        self.child_contracts().insert(address.clone());
+        self.child_contract_code_hash(&address).set(code_hash);
+        self.deploy_contract_event(&address, code_hash);
+
+        address
+    }
+
+    /// Returns the active roles of a user that define or inherit a policy for any of the given permissions.
+    fn get_user_roles_for_permissions(&self, user: UserId, permissions: &ManagedVec<PermissionName<Self::Api>>) -> ManagedVec<RoleName<Self::Api>> {
+        let mut roles = ManagedVec::new();
+
+        for role in self.user_roles(user).iter() {
+            if !self.has_active_role_assignment(user, &role) {
+                continue;
+            }
+
+            if permissions.iter().any(|permission| self.get_role_policy(&role, &permission).is_some()) {
+                roles.push(role);
+            }
+        }
+
+        roles
+    }
+
+    fn clear_contract_stage(&self, address: &ManagedAddress) {
+        self.stage(address).clear();
+        self.stage_code_hash(address).clear();
+        self.stage_stager(address).clear();
+        self.stage_lock(address).clear();
+        self.stage_current_proposal(address).clear();
+    }
+
+    fn calculate_code_hash(&self, code: &ManagedBuffer) -> ManagedBuffer {
+        self.crypto().keccak256(code).as_managed_buffer().clone()
+    }
+
+    fn is_stage_locked(&self, address: &ManagedAddress) -> bool {
+        self.stage_lock(address).get()
+    }
+
+    fn require_caller_has_developer_role(&self) {
+        let caller = self.blockchain().get_caller();
+        let developer_role = ManagedBuffer::from(ROLE_BUILTIN_DEVELOPER);
+
+        require!(self.has_role(&caller, &developer_role), "caller must be developer");
+    }
+
+    #[storage_mapper("contract_stage")]
+    fn stage(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("contract_stage_code_hash")]
+    fn stage_code_hash(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("contract_stage_stager")]
+    fn stage_stager(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("contract_stage_lock")]
+    fn stage_lock(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("contract_stage_proposal")]
+    fn stage_current_proposal(&self, address: &ManagedAddress) -> SingleValueMapper<ProposalId>;
+
+    #[event("deploy_contract")]
+    fn deploy_contract_event(&self, #[indexed] address: &ManagedAddress, #[indexed] code_hash: &ManagedBuffer);
+
+    #[storage_mapper("contract_deploy_stage")]
+    fn deploy_stage(&self, code_hash: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("child_contracts")]
+    fn child_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("child_contract_code_hash")]
+    fn child_contract_code_hash(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("unique_ids")]
+    fn unique_ids(&self) -> UnorderedSetMapper<ManagedBuffer>;
+}
@@ -0,0 +1,443 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use super::staking;
+use crate::config::{
+    self, ProposalId, Timestamp, UserId, CONVICTION_EXECUTION_DELAY_MINUTES_MIN, CONVICTION_PARAMETER_MAX, CONVICTION_PERIODS_MAX,
+    CONVICTION_PERIOD_SECONDS,
+};
+use crate::permission;
+use crate::plug;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ConvictionProposal<M: ManagedTypeApi> {
+    pub id: ProposalId,
+    pub proposer: UserId,
+    pub content_hash: ManagedBuffer<M>,
+    pub beneficiary: ManagedAddress<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub created_at: Timestamp,
+    pub queued_at: Timestamp,
+    pub executed: bool,
+    pub canceled: bool,
+    pub vetoed: bool,
+}
+
+impl<M: ManagedTypeApi> ConvictionProposal<M> {
+    pub fn is_open(&self) -> bool {
+        !self.executed && !self.canceled && !self.vetoed
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued_at > 0
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ConvictionState<M: ManagedTypeApi> {
+    pub support: BigUint<M>,
+    pub conviction: BigUint<M>,
+    pub updated_at: Timestamp,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub enum ConvictionStatus {
+    Active,
+    Passed,
+    Queued,
+    Executed,
+    Canceled,
+    Vetoed,
+}
+
+#[multiversx_sc::module]
+pub trait ConvictionModule:
+    config::ConfigModule + plug::PlugModule + permission::PermissionModule + events::GovEventsModule + staking::StakingModule
+{
+    /// Create a conviction proposal that continuously collects staked support to fund a beneficiary from the treasury.
+    /// Conviction proposals have no voting period and their own ids, apart from regular proposals.
+    /// The proposer needs a stake of at least the minimum propose weight and the conviction weight share of the circulating supply.
+    /// Arguments:
+    ///     - content_hash: hash of the proposal content
+    ///     - beneficiary: address to receive the funds once the proposal passed
+    ///     - token: token to be paid out from the treasury, EGLD or ESDT
+    ///     - amount: amount of tokens requested
+    #[endpoint(proposeConviction)]
+    fn propose_conviction_endpoint(&self, content_hash: ManagedBuffer, beneficiary: ManagedAddress, token: EgldOrEsdtTokenIdentifier, amount: BigUint) -> ProposalId {
+        require!(amount > 0, "amount must be greater than zero");
+
+        let caller = self.blockchain().get_caller();
+        let proposer = self.users().get_or_create_user(&caller);
+        require!(self.stakes(proposer).get() >= self.get_conviction_min_propose_weight(), "insufficient vote weight");
+
+        let id = self.next_conviction_proposal_id().get();
+        let now = self.blockchain().get_block_timestamp();
+
+        self.conviction_proposals(id).set(ConvictionProposal {
+            id,
+            proposer,
+            content_hash,
+            beneficiary,
+            token,
+            amount,
+            created_at: now,
+            queued_at: 0,
+            executed: false,
+            canceled: false,
+            vetoed: false,
+        });
+
+        self.conviction_states(id).set(ConvictionState {
+            support: BigUint::zero(),
+            conviction: BigUint::zero(),
+            updated_at: now,
+        });
+
+        self.next_conviction_proposal_id().set(id + 1);
+        self.conviction_propose_event(caller, id);
+
+        id
+    }
+
+    /// Allocate staked governance tokens as support to a conviction proposal.
+    /// Conviction builds up over time while the support is kept. Allocated stake can not be unstaked.
+    #[endpoint(supportConviction)]
+    fn support_conviction_endpoint(&self, proposal_id: ProposalId, amount: BigUint) {
+        require!(amount > 0, "amount must be greater than zero");
+
+        let proposal = self.get_conviction_proposal_or_fail(proposal_id);
+        require!(proposal.is_open(), "proposal is closed");
+
+        let caller = self.blockchain().get_caller();
+        let supporter = self.users().get_user_id(&caller);
+        require!(supporter != 0, "insufficient unallocated stake");
+
+        let unallocated = self.stakes(supporter).get() - self.stake_allocations(supporter).get();
+        require!(unallocated >= amount, "insufficient unallocated stake");
+
+        self.stake_allocations(supporter).update(|current| *current += &amount);
+        self.conviction_supports(proposal_id, supporter).update(|current| *current += &amount);
+        self.update_conviction_support(proposal_id, |support| *support += &amount);
+
+        self.conviction_support_event(caller, proposal_id, amount);
+    }
+
+    /// Withdraw support from a conviction proposal, releasing the allocated stake.
+    /// Withdrawing from an open proposal stops its conviction from growing with that support.
+    #[endpoint(withdrawConvictionSupport)]
+    fn withdraw_conviction_support_endpoint(&self, proposal_id: ProposalId, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let supporter = self.users().get_user_id(&caller);
+        require!(amount > 0, "amount must be greater than zero");
+        require!(supporter != 0 && self.conviction_supports(proposal_id, supporter).get() >= amount, "insufficient support");
+
+        self.stake_allocations(supporter).update(|current| *current -= &amount);
+        self.conviction_supports(proposal_id, supporter).update(|current| *current -= &amount);
+
+        if self.get_conviction_proposal_or_fail(proposal_id).is_open() {
+            self.update_conviction_support(proposal_id, |support| *support -= &amount);
+        }
+
+        self.conviction_withdraw_support_event(caller, proposal_id, amount);
+    }
+
+    /// Queue a conviction proposal whose conviction crossed its threshold for its payout.
+    /// The payout can be executed once the execution delay has passed, which gives guardians time to veto it.
+    /// Can be called by anyone.
+    #[endpoint(queueConviction)]
+    fn queue_conviction_endpoint(&self, proposal_id: ProposalId) {
+        let mut proposal = self.get_conviction_proposal_or_fail(proposal_id);
+        require!(self.get_conviction_status(&proposal) == ConvictionStatus::Passed, "proposal has not passed");
+
+        proposal.queued_at = self.blockchain().get_block_timestamp();
+        self.conviction_proposals(proposal_id).set(&proposal);
+
+        self.conviction_queue_event(self.blockchain().get_caller(), proposal_id);
+    }
+
+    /// Pay out the requested funds of a queued conviction proposal once its execution delay has passed.
+    /// Can be called by anyone.
+    #[endpoint(executeConviction)]
+    fn execute_conviction_endpoint(&self, proposal_id: ProposalId) {
+        let mut proposal = self.get_conviction_proposal_or_fail(proposal_id);
+        require!(self.get_conviction_status(&proposal) == ConvictionStatus::Queued, "proposal is not queued");
+
+        let executable_at = proposal.queued_at + self.get_conviction_execution_delay_seconds();
+        require!(self.blockchain().get_block_timestamp() >= executable_at, "proposal is still queued");
+        require!(self.get_treasury_balance(&proposal.token) >= proposal.amount, "not enough tokens available");
+
+        proposal.executed = true;
+        self.conviction_proposals(proposal_id).set(&proposal);
+
+        self.tx()
+            .to(&proposal.beneficiary)
+            .egld_or_single_esdt(&proposal.token, 0, &proposal.amount)
+            .transfer();
+
+        self.conviction_execute_event(self.blockchain().get_caller(), proposal_id);
+    }
+
+    /// Cancel an open conviction proposal.
+    /// Can only be called by the proposer or the contract itself.
+    #[endpoint(cancelConviction)]
+    fn cancel_conviction_endpoint(&self, proposal_id: ProposalId) {
+        let mut proposal = self.get_conviction_proposal_or_fail(proposal_id);
+        require!(proposal.is_open(), "proposal is closed");
+
+        let caller = self.blockchain().get_caller();
+        let is_proposer = self.users().get_user_id(&caller) == proposal.proposer;
+        require!(is_proposer || caller == self.blockchain().get_sc_address(), "action not allowed by user");
+
+        proposal.canceled = true;
+        self.conviction_proposals(proposal_id).set(&proposal);
+
+        self.conviction_cancel_event(caller, proposal_id);
+    }
+
+    /// Veto an open conviction proposal before its payout is executed.
+    /// Can only be called by users with the guardian role.
+    /// Arguments:
+    ///     - reason_hash: the hash of the reason for the veto to verify integrity on the frontend
+    #[endpoint(vetoConviction)]
+    fn veto_conviction_endpoint(&self, proposal_id: ProposalId, reason_hash: ManagedBuffer) {
+        self.require_caller_has_guardian_role();
+        require!(!reason_hash.is_empty(), "reason hash must not be empty");
+
+        let mut proposal = self.get_conviction_proposal_or_fail(proposal_id);
+        require!(proposal.is_open(), "proposal is closed");
+
+        proposal.vetoed = true;
+        self.conviction_proposals(proposal_id).set(&proposal);
+
+        self.conviction_veto_event(self.blockchain().get_caller(), proposal_id, &reason_hash);
+    }
+
+    /// Change the parameters of conviction voting.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - decay: share of conviction kept per period of one hour **in basis points**
+    ///     - max_ratio: max share of the treasury a single proposal can request **in basis points**
+    ///     - weight: min share of the circulating supply needed as support for small requests **in basis points**
+    #[endpoint(changeConvictionSettings)]
+    fn change_conviction_settings_endpoint(&self, decay: u64, max_ratio: u64, weight: u64) {
+        self.require_caller_self();
+        require!(decay < CONVICTION_PARAMETER_MAX, "invalid conviction decay");
+        require!(max_ratio > 0 && max_ratio <= CONVICTION_PARAMETER_MAX, "invalid conviction max ratio");
+        require!(weight > 0 && weight <= CONVICTION_PARAMETER_MAX, "invalid conviction weight");
+
+        self.conviction_decay().set(decay);
+        self.conviction_max_ratio().set(max_ratio);
+        self.conviction_weight().set(weight);
+    }
+
+    fn get_conviction_status(&self, proposal: &ConvictionProposal<Self::Api>) -> ConvictionStatus {
+        if proposal.canceled {
+            return ConvictionStatus::Canceled;
+        }
+
+        if proposal.vetoed {
+            return ConvictionStatus::Vetoed;
+        }
+
+        if proposal.executed {
+            return ConvictionStatus::Executed;
+        }
+
+        if proposal.is_queued() {
+            return ConvictionStatus::Queued;
+        }
+
+        if self.has_sufficient_conviction(proposal) {
+            return ConvictionStatus::Passed;
+        }
+
+        ConvictionStatus::Active
+    }
+
+    fn has_sufficient_conviction(&self, proposal: &ConvictionProposal<Self::Api>) -> bool {
+        let now = self.blockchain().get_block_timestamp();
+        let conviction = self.get_conviction_state_at(proposal.id, now).conviction;
+
+        match self.get_conviction_threshold(proposal) {
+            Some(threshold) => conviction > 0 && conviction >= threshold,
+            None => false,
+        }
+    }
+
+    /// Returns the conviction a proposal needs to pass, or none if it requests the max ratio of the treasury or more
+    /// or there is no circulating supply to measure support against.
+    /// The threshold grows with the requested share of the treasury, so that a proposal requesting share `r` passes
+    /// once supported by `weight / (1 - r / max_ratio)^2` of the circulating supply for long enough.
+    fn get_conviction_threshold(&self, proposal: &ConvictionProposal<Self::Api>) -> Option<BigUint> {
+        let treasury = self.get_treasury_balance(&proposal.token);
+
+        if treasury == 0 {
+            return None;
+        }
+
+        let max_ratio = BigUint::from(self.conviction_max_ratio().get());
+        let requested_ratio = &proposal.amount * CONVICTION_PARAMETER_MAX / &treasury;
+
+        if requested_ratio >= max_ratio {
+            return None;
+        }
+
+        let supply = self.get_circulating_gov_token_supply();
+
+        if supply == 0 {
+            return None;
+        }
+
+        let remaining_ratio = &max_ratio - &requested_ratio;
+        let weight = self.conviction_weight().get();
+        let decay = self.conviction_decay().get();
+
+        // the max conviction of a constant support equals the support divided by (1 - decay)
+        let numerator = supply * weight * &max_ratio * &max_ratio;
+        let denominator = &remaining_ratio * &remaining_ratio * (CONVICTION_PARAMETER_MAX - decay);
+
+        Some(numerator / denominator)
+    }
+
+    /// Applies the conviction gained over all full periods since the last update, then changes the support.
+    fn update_conviction_support<F: FnOnce(&mut BigUint)>(&self, proposal: ProposalId, f: F) {
+        let now = self.blockchain().get_block_timestamp();
+        let mut state = self.get_conviction_state_at(proposal, now);
+        f(&mut state.support);
+
+        self.conviction_states(proposal).set(&state);
+    }
+
+    /// Returns the conviction state of a proposal at the given timestamp.
+    /// Each full period, the conviction decays and the current support is added to it.
+    fn get_conviction_state_at(&self, proposal: ProposalId, timestamp: Timestamp) -> ConvictionState<Self::Api> {
+        let mut state = self.conviction_states(proposal).get();
+
+        if timestamp <= state.updated_at {
+            return state;
+        }
+
+        let periods = (timestamp - state.updated_at) / CONVICTION_PERIOD_SECONDS;
+        let decay = self.conviction_decay().get();
+
+        for _ in 0..periods.min(CONVICTION_PERIODS_MAX) {
+            state.conviction = &state.conviction * decay / CONVICTION_PARAMETER_MAX + &state.support;
+        }
+
+        state.updated_at += periods * CONVICTION_PERIOD_SECONDS;
+
+        state
+    }
+
+    /// Returns the stake needed to create a conviction proposal: the min propose weight, but at least the conviction weight share of the circulating supply.
+    fn get_conviction_min_propose_weight(&self) -> BigUint {
+        let min_propose_weight = self.min_propose_weight().get();
+        let supply_share = self.get_circulating_gov_token_supply() * self.conviction_weight().get() / CONVICTION_PARAMETER_MAX;
+
+        core::cmp::max(min_propose_weight, supply_share)
+    }
+
+    /// Returns the delay between queueing and executing a payout: the default execution delay, but at least one day.
+    fn get_conviction_execution_delay_seconds(&self) -> u64 {
+        let delay_minutes = self.default_execution_delay_minutes().get().max(CONVICTION_EXECUTION_DELAY_MINUTES_MIN);
+
+        delay_minutes as u64 * 60
+    }
+
+    /// Returns the balance of a token in the treasury, excluding governance tokens guarded for voting.
+    fn get_treasury_balance(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(token, 0);
+
+        if token.is_egld() {
+            return balance;
+        }
+
+        let guarded = self.guarded_vote_tokens(&token.clone().unwrap_esdt(), 0).get();
+
+        if balance > guarded {
+            balance - guarded
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    fn get_conviction_proposal_or_fail(&self, proposal: ProposalId) -> ConvictionProposal<Self::Api> {
+        self.require_conviction_proposal_exists(proposal);
+
+        self.conviction_proposals(proposal).get()
+    }
+
+    #[view(getConvictionProposal)]
+    fn get_conviction_proposal_view(&self, proposal: ProposalId) -> ConvictionProposal<Self::Api> {
+        self.get_conviction_proposal_or_fail(proposal)
+    }
+
+    #[view(getConvictionStatus)]
+    fn get_conviction_status_view(&self, proposal: ProposalId) -> ConvictionStatus {
+        let proposal = self.get_conviction_proposal_or_fail(proposal);
+
+        self.get_conviction_status(&proposal)
+    }
+
+    /// Returns the current support and conviction of a conviction proposal.
+    #[view(getConviction)]
+    fn get_conviction_view(&self, proposal: ProposalId) -> MultiValue2<BigUint, BigUint> {
+        self.require_conviction_proposal_exists(proposal);
+
+        let state = self.get_conviction_state_at(proposal, self.blockchain().get_block_timestamp());
+
+        (state.support, state.conviction).into()
+    }
+
+    #[view(getConvictionThreshold)]
+    fn get_conviction_threshold_view(&self, proposal: ProposalId) -> OptionalValue<BigUint> {
+        let proposal = self.get_conviction_proposal_or_fail(proposal);
+
+        self.get_conviction_threshold(&proposal).into()
+    }
+
+    #[view(getConvictionMinProposeWeight)]
+    fn get_conviction_min_propose_weight_view(&self) -> BigUint {
+        self.get_conviction_min_propose_weight()
+    }
+
+    #[view(getConvictionSupport)]
+    fn get_conviction_support_view(&self, proposal: ProposalId, address: ManagedAddress) -> BigUint {
+        let supporter = self.users().get_user_id(&address);
+
+        self.conviction_supports(proposal, supporter).get()
+    }
+
+    fn require_conviction_proposal_exists(&self, proposal: ProposalId) {
+        require!(!self.conviction_proposals(proposal).is_empty(), "proposal does not exist");
+    }
+
+    #[storage_mapper("conviction:next_proposal_id")]
+    fn next_conviction_proposal_id(&self) -> SingleValueMapper<ProposalId>;
+
+    #[storage_mapper("conviction:proposals")]
+    fn conviction_proposals(&self, proposal: ProposalId) -> SingleValueMapper<ConvictionProposal<Self::Api>>;
+
+    #[storage_mapper("conviction:states")]
+    fn conviction_states(&self, proposal: ProposalId) -> SingleValueMapper<ConvictionState<Self::Api>>;
+
+    #[storage_mapper("conviction:supports")]
+    fn conviction_supports(&self, proposal: ProposalId, supporter: UserId) -> SingleValueMapper<BigUint>;
+
+    #[view(getConvictionDecay)]
+    #[storage_mapper("conviction:decay")]
+    fn conviction_decay(&self) -> SingleValueMapper<u64>;
+
+    #[view(getConvictionMaxRatio)]
+    #[storage_mapper("conviction:max_ratio")]
+    fn conviction_max_ratio(&self) -> SingleValueMapper<u64>;
+
+    #[view(getConvictionWeight)]
+    #[storage_mapper("conviction:weight")]
+    fn conviction_weight(&self) -> SingleValueMapper<u64>;
+}
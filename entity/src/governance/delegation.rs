@@ -0,0 +1,156 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use super::staking;
+use crate::config::{self, Checkpoint, ProposalId, Timestamp, UserId};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct DelegateCheckpoint {
+    pub timestamp: Timestamp,
+    pub delegate: UserId,
+}
+
+impl Checkpoint for DelegateCheckpoint {
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+#[multiversx_sc::module]
+pub trait DelegationModule: config::ConfigModule + events::GovEventsModule + staking::StakingModule {
+    /// Delegate the governance voting power of the caller to another address, e.g. an agent.
+    /// The stake of the caller counts toward the votes of the delegate unless the caller votes directly.
+    /// Escrowed tokens only count toward direct votes.
+    /// Calling it again moves the delegation to the new delegate and adds optionally sent tokens to the deposit.
+    /// Payment (optional):
+    ///     - token id must be equal to configured governance token id
+    ///     - tokens must be fungible
+    ///     - tokens are staked for the caller and count as the delegation deposit
+    #[payable("*")]
+    #[endpoint(delegate)]
+    fn delegate_endpoint(&self, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(caller != address, "can not delegate to self");
+
+        let payments = self.call_value().all_esdt_transfers();
+        self.require_payments_with_primary_gov_token(&payments);
+
+        let delegator = self.users().get_or_create_user(&caller);
+        let delegate = self.users().get_or_create_user(&address);
+
+        let previous_delegate = self.delegates(delegator).get();
+        if previous_delegate != delegate {
+            let stake = self.stakes(delegator).get();
+
+            if previous_delegate != 0 {
+                self.delegators(previous_delegate).swap_remove(&delegator);
+                self.remove_delegated_stake(previous_delegate, &stake);
+            }
+
+            self.add_delegated_stake(delegate, &stake);
+        }
+
+        self.delegates(delegator).set(delegate);
+        self.delegators(delegate).insert(delegator);
+        self.write_delegate_checkpoint(delegator, delegate);
+
+        if !payments.is_empty() {
+            let amount = self.stake_payments(delegator, &payments);
+            self.delegated_tokens(delegator).update(|current| *current += &amount);
+        }
+
+        self.delegate_event(caller, address, self.delegated_tokens(delegator).get());
+    }
+
+    /// Revoke the delegation of the caller and unstake the deposit, which can be claimed with `claimUnstaked` after unbonding.
+    /// Fails while the deposit counts toward a proposal that is still in its voting period.
+    /// Votes already cast by the delegate on behalf of the caller remain until the caller votes directly.
+    #[endpoint(undelegate)]
+    fn undelegate_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let delegator = self.users().get_user_id(&caller);
+        require!(delegator != 0 && !self.delegates(delegator).is_empty(), "no delegation");
+
+        // the deposit is part of the stake and may have been unstaked separately
+        let deposit = self.delegated_tokens(delegator).take();
+        let stake = self.stakes(delegator).get();
+        let amount = if deposit < stake { deposit } else { stake };
+
+        if amount > 0 {
+            self.unstake(delegator, &amount);
+        }
+
+        let delegate = self.delegates(delegator).take();
+        self.delegators(delegate).swap_remove(&delegator);
+        self.remove_delegated_stake(delegate, &self.stakes(delegator).get());
+        self.write_delegate_checkpoint(delegator, 0);
+
+        let delegate_address = self.users().get_user_address_unchecked(delegate);
+        self.undelegate_event(caller, delegate_address, amount);
+    }
+
+    /// Records the delegate of a delegator from now on. A delegate of zero means no delegation.
+    fn write_delegate_checkpoint(&self, delegator: UserId, delegate: UserId) {
+        let now = self.blockchain().get_block_timestamp();
+
+        config::write_checkpoint(&mut self.delegate_checkpoints(delegator), &DelegateCheckpoint { timestamp: now, delegate });
+    }
+
+    /// Returns the delegate of a delegator right before the given timestamp, or zero if there was none.
+    fn get_delegate_at(&self, delegator: UserId, timestamp: Timestamp) -> UserId {
+        config::find_checkpoint_before(&self.delegate_checkpoints(delegator), timestamp).map_or(0, |checkpoint| checkpoint.delegate)
+    }
+
+    #[view(getDelegate)]
+    fn get_delegate_view(&self, address: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let delegator = self.users().get_user_id(&address);
+
+        if delegator == 0 || self.delegates(delegator).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.users().get_user_address_unchecked(self.delegates(delegator).get()))
+    }
+
+    #[view(getDelegators)]
+    fn get_delegators_view(&self, address: ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let delegate = self.users().get_user_id(&address);
+        let mut delegators = MultiValueEncoded::new();
+
+        if delegate == 0 {
+            return delegators;
+        }
+
+        for delegator in self.delegators(delegate).iter() {
+            delegators.push(self.users().get_user_address_unchecked(delegator));
+        }
+
+        delegators
+    }
+
+    #[view(getDelegatedTokens)]
+    fn get_delegated_tokens_view(&self, address: ManagedAddress) -> BigUint {
+        let delegator = self.users().get_user_id(&address);
+
+        self.delegated_tokens(delegator).get()
+    }
+
+    #[storage_mapper("delegation:delegators")]
+    fn delegators(&self, delegate: UserId) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("delegation:checkpoints")]
+    fn delegate_checkpoints(&self, delegator: UserId) -> VecMapper<DelegateCheckpoint>;
+
+    #[storage_mapper("delegation:tokens")]
+    fn delegated_tokens(&self, delegator: UserId) -> SingleValueMapper<BigUint>;
+
+    /// Delegates who have voted on a proposal with the stake delegated to them.
+    #[storage_mapper("delegation:delegate_voters")]
+    fn proposal_delegate_voters(&self, proposal: ProposalId) -> UnorderedSetMapper<UserId>;
+
+    /// Stake of delegators who voted directly before their delegate, excluded from the delegate's vote.
+    #[storage_mapper("delegation:excluded")]
+    fn excluded_delegated_stakes(&self, proposal: ProposalId) -> MapMapper<UserId, BigUint>;
+}
@@ -0,0 +1,174 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use crate::config::{self, Checkpoint, Timestamp, UserId, ESCROW_LOCK_MINUTES_MAX};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct EscrowLock<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlocks_at: Timestamp,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct EscrowCheckpoint<M: ManagedTypeApi> {
+    pub timestamp: Timestamp,
+    pub lock: EscrowLock<M>,
+}
+
+impl<M: ManagedTypeApi> Checkpoint for EscrowCheckpoint<M> {
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+#[multiversx_sc::module]
+pub trait EscrowModule: config::ConfigModule + events::GovEventsModule {
+    /// Lock governance tokens in escrow for a chosen duration to gain time-weighted voting power.
+    /// The voting power equals the locked amount scaled by the remaining lock time relative to the max lock time,
+    /// and decays linearly until the lock expires. Locking again adds sent tokens and extends the lock if longer.
+    /// Payment (optional when extending an existing lock):
+    ///     - token id must be equal to configured governance token id
+    ///     - tokens must be fungible
+    /// Arguments:
+    ///     - lock_minutes: lock duration from now **in minutes**
+    #[payable("*")]
+    #[endpoint(lockEscrow)]
+    fn lock_escrow_endpoint(&self, lock_minutes: usize) {
+        require!(lock_minutes > 0, "lock duration must be greater than zero");
+        require!(lock_minutes <= ESCROW_LOCK_MINUTES_MAX, "max lock duration exceeded");
+
+        let caller = self.blockchain().get_caller();
+        let payments = self.call_value().all_esdt_transfers();
+        self.require_payments_with_primary_gov_token(&payments);
+
+        let user = self.users().get_or_create_user(&caller);
+        let amount = payments.iter().fold(BigUint::zero(), |carry, payment| {
+            require!(payment.token_nonce == 0, "escrowed tokens must be fungible");
+            carry + &payment.amount
+        });
+
+        let now = self.blockchain().get_block_timestamp();
+        let mut lock = self.get_escrow_lock(user);
+        let unlocks_at = now + lock_minutes as u64 * 60;
+
+        lock.amount += &amount;
+        require!(lock.amount > 0, "nothing to lock");
+
+        if lock.unlocks_at < unlocks_at {
+            lock.unlocks_at = unlocks_at;
+        }
+
+        if amount > 0 {
+            let gov_token = self.gov_token().get();
+            self.guarded_vote_tokens(&gov_token, 0).update(|current| *current += &amount);
+        }
+
+        self.escrow_lock_event(caller, lock.amount.clone(), lock.unlocks_at);
+        self.escrow_locks(user).set(&lock);
+        self.write_escrow_checkpoint(user, lock);
+    }
+
+    /// Withdraw escrowed governance tokens once the lock has expired
+    /// and the escrow no longer counts toward a proposal in its voting period.
+    #[endpoint(withdrawEscrow)]
+    fn withdraw_escrow_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let user = self.users().get_user_id(&caller);
+        require!(user != 0 && !self.escrow_locks(user).is_empty(), "nothing to withdraw");
+
+        let now = self.blockchain().get_block_timestamp();
+        let lock = self.escrow_locks(user).get();
+        require!(now >= lock.unlocks_at, "escrow is locked");
+        require!(now >= self.escrow_vote_unlocks_at(user).get(), "escrow is used in active votes");
+
+        self.escrow_locks(user).clear();
+
+        self.write_escrow_checkpoint(
+            user,
+            EscrowLock {
+                amount: BigUint::zero(),
+                unlocks_at: 0,
+            },
+        );
+
+        let gov_token = self.gov_token().get();
+        self.guarded_vote_tokens(&gov_token, 0).update(|current| *current -= &lock.amount);
+        self.tx().to(&caller).single_esdt(&gov_token, 0, &lock.amount).transfer();
+
+        self.escrow_withdraw_event(caller, lock.amount);
+    }
+
+    /// Keeps the escrow of a user from being withdrawn until the voting period of the proposal has ended.
+    fn lock_escrow_votes_until(&self, user: UserId, until: Timestamp) {
+        self.escrow_vote_unlocks_at(user).update(|unlocks_at| {
+            if *unlocks_at < until {
+                *unlocks_at = until;
+            }
+        });
+    }
+
+    fn get_escrow_lock(&self, user: UserId) -> EscrowLock<Self::Api> {
+        if self.escrow_locks(user).is_empty() {
+            return EscrowLock {
+                amount: BigUint::zero(),
+                unlocks_at: 0,
+            };
+        }
+
+        self.escrow_locks(user).get()
+    }
+
+    fn write_escrow_checkpoint(&self, user: UserId, lock: EscrowLock<Self::Api>) {
+        let checkpoint = EscrowCheckpoint {
+            timestamp: self.blockchain().get_block_timestamp(),
+            lock,
+        };
+
+        config::write_checkpoint(&mut self.escrow_checkpoints(user), &checkpoint);
+    }
+
+    /// Returns the escrow voting power of a user at the given timestamp,
+    /// based on the lock held right before it. Locks created at or after the timestamp carry no weight.
+    fn get_escrow_power_at(&self, user: UserId, timestamp: Timestamp) -> BigUint {
+        let lock = match config::find_checkpoint_before(&self.escrow_checkpoints(user), timestamp) {
+            Some(checkpoint) => checkpoint.lock,
+            None => return BigUint::zero(),
+        };
+
+        if lock.unlocks_at <= timestamp {
+            return BigUint::zero();
+        }
+
+        let remaining = lock.unlocks_at - timestamp;
+        let max_lock = ESCROW_LOCK_MINUTES_MAX as u64 * 60;
+
+        lock.amount * remaining / max_lock
+    }
+
+    #[view(getEscrow)]
+    fn get_escrow_view(&self, address: ManagedAddress) -> MultiValue2<BigUint, Timestamp> {
+        let user = self.users().get_user_id(&address);
+        let lock = self.get_escrow_lock(user);
+
+        (lock.amount, lock.unlocks_at).into()
+    }
+
+    #[view(getEscrowPowerAt)]
+    fn get_escrow_power_at_view(&self, address: ManagedAddress, timestamp: Timestamp) -> BigUint {
+        let user = self.users().get_user_id(&address);
+
+        self.get_escrow_power_at(user, timestamp)
+    }
+
+    #[storage_mapper("escrow:locks")]
+    fn escrow_locks(&self, user: UserId) -> SingleValueMapper<EscrowLock<Self::Api>>;
+
+    #[storage_mapper("escrow:checkpoints")]
+    fn escrow_checkpoints(&self, user: UserId) -> VecMapper<EscrowCheckpoint<Self::Api>>;
+
+    #[storage_mapper("escrow:vote_unlocks_at")]
+    fn escrow_vote_unlocks_at(&self, user: UserId) -> SingleValueMapper<Timestamp>;
+}
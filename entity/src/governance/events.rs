@@ -1,6 +1,7 @@
 multiversx_sc::imports!();
 
 use crate::config::{self, ProposalId, ProposalOptionId, UserId};
+use crate::permission::RoleName;
 
 use super::proposal::{Proposal, VoteType};
 
@@ -22,9 +23,18 @@ pub trait GovEventsModule: config::ConfigModule {
             VoteType::Against => {
                 self.vote_against_event(voter, proposal.id, weight, poll_option);
             }
+            VoteType::Abstain => {
+                self.vote_abstain_event(voter, proposal.id, weight, poll_option);
+            }
         }
     }
 
+    fn emit_vote_change_event(&self, voter: UserId, proposal: &Proposal<Self::Api>, vote_type: VoteType, weight: BigUint, poll_option: ProposalOptionId) {
+        let voter = self.users().get_user_address_unchecked(voter);
+
+        self.vote_change_event(voter, proposal.id, vote_type, weight, poll_option);
+    }
+
     fn emit_sign_event(&self, signer: ManagedAddress, proposal: &Proposal<Self::Api>, poll_option: ProposalOptionId) {
         self.sign_event(signer, proposal.id, poll_option);
     }
@@ -41,6 +51,10 @@ pub trait GovEventsModule: config::ConfigModule {
         self.cancel_event(self.blockchain().get_caller(), proposal.id);
     }
 
+    fn emit_veto_event(&self, proposal: &Proposal<Self::Api>, reason_hash: &ManagedBuffer) {
+        self.veto_event(self.blockchain().get_caller(), proposal.id, reason_hash);
+    }
+
     fn emit_withdraw_event(&self, proposal: &Proposal<Self::Api>) {
         self.withdraw_event(self.blockchain().get_caller(), proposal.id);
     }
@@ -58,6 +72,19 @@ pub trait GovEventsModule: config::ConfigModule {
     #[event("vote_against")]
     fn vote_against_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] weight: BigUint, #[indexed] poll_option: u8);
 
+    #[event("vote_abstain")]
+    fn vote_abstain_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] weight: BigUint, #[indexed] poll_option: u8);
+
+    #[event("vote_change")]
+    fn vote_change_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] proposal: ProposalId,
+        #[indexed] vote_type: VoteType,
+        #[indexed] weight: BigUint,
+        #[indexed] poll_option: u8,
+    );
+
     #[event("sign")]
     fn sign_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] poll_option: u8);
 
@@ -70,9 +97,69 @@ pub trait GovEventsModule: config::ConfigModule {
     #[event("cancel")]
     fn cancel_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
 
+    #[event("veto")]
+    fn veto_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] reason_hash: &ManagedBuffer);
+
+    #[event("delegate")]
+    fn delegate_event(&self, #[indexed] caller: ManagedAddress, #[indexed] delegate: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("undelegate")]
+    fn undelegate_event(&self, #[indexed] caller: ManagedAddress, #[indexed] delegate: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("stake")]
+    fn stake_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("unstake")]
+    fn unstake_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint, #[indexed] unbonds_at: u64);
+
+    #[event("claim_unstaked")]
+    fn claim_unstaked_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("escrow_lock")]
+    fn escrow_lock_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint, #[indexed] unlocks_at: u64);
+
+    #[event("escrow_withdraw")]
+    fn escrow_withdraw_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("conviction_propose")]
+    fn conviction_propose_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
+
+    #[event("conviction_support")]
+    fn conviction_support_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] amount: BigUint);
+
+    #[event("conviction_withdraw_support")]
+    fn conviction_withdraw_support_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] amount: BigUint);
+
+    #[event("conviction_queue")]
+    fn conviction_queue_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
+
+    #[event("conviction_veto")]
+    fn conviction_veto_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId, #[indexed] reason_hash: &ManagedBuffer);
+
+    #[event("conviction_execute")]
+    fn conviction_execute_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
+
+    #[event("conviction_cancel")]
+    fn conviction_cancel_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
+
+    #[event("vote_abort")]
+    fn vote_abort_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
+
+    #[event("plug_failed")]
+    fn plug_failed_event(&self, #[indexed] plug: ManagedAddress, #[indexed] proposal: ProposalId);
+
     #[event("withdraw")]
     fn withdraw_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: ProposalId);
 
     #[event("withdraw_progress")]
     fn withdraw_progress_event(&self, #[indexed] total: usize, #[indexed] withdrawn: usize);
+
+    #[event("role_admin_assign")]
+    fn role_admin_assign_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] role: &RoleName<Self::Api>, #[indexed] address: &ManagedAddress);
+
+    #[event("role_admin_unassign")]
+    fn role_admin_unassign_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] role: &RoleName<Self::Api>, #[indexed] address: &ManagedAddress);
+
+    #[event("role_expire")]
+    fn role_expire_event(&self, #[indexed] role: &RoleName<Self::Api>, #[indexed] address: &ManagedAddress);
 }
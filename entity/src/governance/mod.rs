@@ -1,24 +1,40 @@
 multiversx_sc::imports!();
 
 use crate::config::{
-    self, LockedVote, ProposalId, ProposalOptionId, Timestamp, UserId, MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT, POLL_MAX_OPTIONS, QUORUM_DEFAULT, TOKEN_MAX_DECIMALS, VOTING_PERIOD_MINUTES_DEFAULT
+    self, LockedVote, ProposalId, ProposalOptionId, Timestamp, UserId, CONVICTION_DECAY_DEFAULT, CONVICTION_MAX_RATIO_DEFAULT, CONVICTION_WEIGHT_DEFAULT,
+    GAS_LIMIT_PLUG_WEIGHT, GAS_LIMIT_PLUG_WEIGHT_CALLBACK, MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT, PLUG_MULTIPLIER_BASE, PLUG_MULTIPLIER_MAX, POLL_MAX_OPTIONS,
+    QUORUM_DEFAULT, TOKEN_MAX_DECIMALS, UNBONDING_PERIOD_MINUTES_DEFAULT, VOTING_PERIOD_MINUTES_DEFAULT
 };
 use crate::errors::ALREADY_VOTED_WITH_TOKEN;
-use crate::permission::{RoleName, ROLE_BUILTIN_MEMBER};
+use crate::permission::{QuorumMode, RoleName, ROLE_BUILTIN_MEMBER};
+use crate::plug::{PlugFailurePolicy, PlugSettings, PlugWeightRequest};
 use crate::{permission, plug};
-use proposal::{Action, ProposalStatus, VoteType};
+use proposal::{Action, ProposalStatus, VoteReceipt, VoteType};
 
 use self::proposal::Proposal;
 
+pub mod conviction;
+pub mod delegation;
+pub mod escrow;
 pub mod events;
 pub mod proposal;
+pub mod staking;
 pub mod token;
 
 use plug::plug_proxy::ProxyTrait as _;
 
 #[multiversx_sc::module]
 pub trait GovernanceModule:
-    config::ConfigModule + plug::PlugModule + permission::PermissionModule + events::GovEventsModule + proposal::ProposalModule + token::TokenModule
+    config::ConfigModule
+    + plug::PlugModule
+    + permission::PermissionModule
+    + events::GovEventsModule
+    + staking::StakingModule
+    + escrow::EscrowModule
+    + delegation::DelegationModule
+    + conviction::ConvictionModule
+    + proposal::ProposalModule
+    + token::TokenModule
 {
     fn init_governance_module(&self) {
         self.next_proposal_id().set_if_empty(1);
@@ -27,16 +43,36 @@ pub trait GovernanceModule:
         self.default_voting_period_minutes().set_if_empty(VOTING_PERIOD_MINUTES_DEFAULT);
         self.min_propose_weight().set_if_empty(BigUint::from(MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT));
         self.default_quorum().set_if_empty(BigUint::from(QUORUM_DEFAULT));
+        self.unbonding_period_minutes().set_if_empty(UNBONDING_PERIOD_MINUTES_DEFAULT);
+        self.next_conviction_proposal_id().set_if_empty(1);
+        self.conviction_decay().set_if_empty(CONVICTION_DECAY_DEFAULT);
+        self.conviction_max_ratio().set_if_empty(CONVICTION_MAX_RATIO_DEFAULT);
+        self.conviction_weight().set_if_empty(CONVICTION_WEIGHT_DEFAULT);
     }
 
     /// Change the governance default quorum.
     /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: absolute vote weight, or share of the circulating voting power **in basis points** when using the percentage mode
+    ///     - mode (optional): either `Absolute` (default) or `Percentage`
     #[endpoint(changeQuorum)]
-    fn change_quorum_endpoint(&self, value: BigUint) {
+    fn change_quorum_endpoint(&self, value: BigUint, opt_mode: OptionalValue<QuorumMode>) {
         self.require_caller_self();
+
+        let mode = opt_mode.into_option().unwrap_or(QuorumMode::Absolute);
+
+        self.try_change_default_quorum_mode(&value, mode);
         self.try_change_default_quorum(value);
     }
 
+    /// Change the absolute quorum applied to percentage quorums while a plug does not report its total weight.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeQuorumFallback)]
+    fn change_quorum_fallback_endpoint(&self, value: BigUint) {
+        self.require_caller_self();
+        self.try_change_quorum_fallback(value);
+    }
+
     /// Change the minimum weight required to vote.
     /// Can only be called by the contract itself.
     #[endpoint(changeMinVoteWeight)]
@@ -63,16 +99,41 @@ pub trait GovernanceModule:
         self.try_change_default_voting_period_minutes(value);
     }
 
+    /// Change the default delay between a proposal succeeding and its actions becoming executable.
+    /// Policies may require a longer delay, in which case the longest applies.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: execution delay duration **in minutes**
+    #[endpoint(changeExecutionDelayMinutes)]
+    fn change_execution_delay_in_minutes_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        self.try_change_default_execution_delay_minutes(value);
+    }
+
+    /// Change the default time window in which succeeded proposals can be executed before they expire.
+    /// The window starts once the execution delay has passed. Zero disables expiry.
+    /// Policies may require a shorter window, in which case the shortest applies.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: execution window duration **in minutes**
+    #[endpoint(changeExecutionWindowMinutes)]
+    fn change_execution_window_in_minutes_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        self.try_change_default_execution_window_minutes(value);
+    }
+
     /// Set token nonces that are allowed to vote.
     /// Can only be called by the contract itself.
     #[endpoint(setRestrictedVoteNonces)]
     fn set_restricted_vote_nonces_endpoint(&self, nonces: MultiValueEncoded<u64>) {
         self.require_caller_self();
         self.restricted_vote_nonces().clear();
-        self.restricted_vote_nonces().extend(nonces.into_iter());
+        self.restricted_vote_nonces().extend(nonces);
     }
 
-    /// Set the address of the plug smart contract.
+    /// Set the address of the main plug smart contract.
+    /// Plug weights are scaled from the given weight decimals to the decimals of the governance token, if one is set.
+    /// The main plug counts 1x and aborts the vote if its weight can not be retrieved.
     /// Can only be called by the contract itself.
     /// Can only be called once.
     #[endpoint(setPlug)]
@@ -82,10 +143,71 @@ pub trait GovernanceModule:
 
         self.plug_contract().set(&address);
         self.plug_weight_decimals().set(weight_decimals);
+        self.plugs().insert(
+            address,
+            PlugSettings {
+                multiplier: PLUG_MULTIPLIER_BASE,
+                weight_decimals,
+                failure_policy: PlugFailurePolicy::Abort,
+            },
+        );
         self.try_change_default_quorum(quorum);
         self.try_change_min_propose_weight(min_propose_weight);
     }
 
+    /// Add or update an additional plug smart contract. The weights of all plugs are summed.
+    /// Arguments:
+    ///     - multiplier: vote weight per plug weight unit in basis points, where 10_000 counts 1x
+    ///     - weight_decimals: the decimals of the weights reported by the plug
+    ///     - failure_policy: how a failed weight query is handled (ignore, zero or abort)
+    /// Can only be called by the contract itself.
+    #[endpoint(addPlug)]
+    fn add_plug_endpoint(&self, address: ManagedAddress, multiplier: u64, weight_decimals: u8, failure_policy: PlugFailurePolicy) {
+        self.require_caller_self();
+        require!(multiplier <= PLUG_MULTIPLIER_MAX, "max multiplier exceeded");
+        require!(weight_decimals <= TOKEN_MAX_DECIMALS, "invalid weight decimals");
+
+        self.plugs().insert(
+            address,
+            PlugSettings {
+                multiplier,
+                weight_decimals,
+                failure_policy,
+            },
+        );
+    }
+
+    /// Remove a plug smart contract. Pending weight queries to the plug are not counted.
+    /// Can only be called by the contract itself.
+    #[endpoint(removePlug)]
+    fn remove_plug_endpoint(&self, address: ManagedAddress) {
+        self.require_caller_self();
+        require!(self.plugs().remove(&address).is_some(), "plug does not exist");
+        self.plug_reports_total_weight(&address).clear();
+
+        // keeps the main plug from being registered again on upgrade
+        if self.get_plug_option() == Some(address) {
+            self.plug_contract().clear();
+        }
+    }
+
+    /// Enable or disable querying the total weight of a plug through `getDaoVoteWeightTotal`.
+    /// Percentage quorums use the quorum fallback while any plug does not report its total weight.
+    /// The plug must be deployed in the same shard as the entity to report its total weight.
+    /// Can only be called by the contract itself.
+    #[endpoint(setPlugTotalWeightReporting)]
+    fn set_plug_total_weight_reporting_endpoint(&self, address: ManagedAddress, enabled: bool) {
+        self.require_caller_self();
+        require!(self.plugs().contains_key(&address), "plug does not exist");
+
+        if enabled {
+            let shard = self.blockchain().get_shard_of_address(&self.blockchain().get_sc_address());
+            require!(self.blockchain().get_shard_of_address(&address) == shard, "plug must be in the same shard");
+        }
+
+        self.plug_reports_total_weight(&address).set(enabled);
+    }
+
     #[endpoint(eject)]
     fn eject_endpoint(&self, opt_trusted_host: OptionalValue<ManagedAddress>) {
         // TODO: check how to guard this
@@ -126,8 +248,6 @@ pub trait GovernanceModule:
         let proposer = self.users().get_or_create_user(&proposer_address);
         let payments = self.call_value().all_esdt_transfers().clone_value();
 
-        self.persist_proposal_results();
-
         self.require_payments_with_gov_token(&payments);
         self.require_vote_tokens_allowed(&payments);
 
@@ -143,57 +263,16 @@ pub trait GovernanceModule:
             starts_at,
             payment_weight.clone(),
             roles.to_vec(),
+            ManagedBuffer::new(),
         );
 
         self.commit_vote_payments(proposer, &proposal, &payments);
 
-        if self.is_plugged() {
-            let token = self.get_gov_token_option();
-            let plug = self.plug_contract().get();
-
-            self.record_plug_vote(proposer, proposal.id);
-
-            self.tx()
-                .legacy_proxy_call(self.plug_proxy(plug).get_dao_vote_weight_view(&proposer_address, OptionalValue::from(token)))
-                .callback(GovernanceModule::callbacks(self).propose_async_callback(
-                    proposal.id,
-                    proposer,
-                    payment_weight,
-                ))
-                .async_call_and_exit();
-        }
+        self.request_plug_weights(&proposer_address, proposer, proposal.id, Option::None, option, payment_weight);
 
         proposal.id
     }
 
-    /// Create a proposal via an asynchronous callback.
-    /// Used majorly via the plugging feature.
-    #[callback]
-    fn propose_async_callback(
-        &self,
-        proposal: ProposalId,
-        proposer: UserId,
-        payment_weight: BigUint,
-        #[call_result] result: ManagedAsyncCallResult<BigUint>,
-    ) {
-        let mut proposal = self.proposals(proposal.id).get();
-
-        if result == ManagedAsyncCallResult::Err {
-            self.cancel_proposal(proposal);
-        }
-
-        let plug_weight = result.unwrap_or_default();
-        let total_weight = &payment_weight + &plug_weight;
-
-        if total_weight >= self.min_vote_weight().get() {
-            self.activate_proposal(proposal);
-        } else {
-            self.cancel_proposal(proposal);
-        }
-
-        // TODO: check to persist proposal results when implemented
-    }
-
     /// Vote for of a proposal, optionally with a poll option.
     /// Payment (optional):
     ///     - token id must be equal to configured governance token id
@@ -214,14 +293,8 @@ pub trait GovernanceModule:
         self.require_vote_tokens_allowed(&payments);
         self.commit_vote_payments(voter, &proposal, &payments);
 
-        if self.is_plugged() {
-            let token = self.get_gov_token_option();
-            let plug = self.plug_contract().get();
-
-            self.tx()
-                .legacy_proxy_call(self.plug_proxy(plug).get_dao_vote_weight_view(&caller, OptionalValue::from(token)))
-                .callback(GovernanceModule::callbacks(self).vote_async_callback(voter, payment_weight, proposal.id, VoteType::For, option_id))
-                .async_call_and_exit();
+        if self.request_plug_weights(&caller, voter, proposal.id, Option::Some(VoteType::For), option_id, payment_weight.clone()) {
+            return;
         }
 
         self.vote(voter, proposal, VoteType::For, payment_weight, option_id);
@@ -247,53 +320,169 @@ pub trait GovernanceModule:
         self.require_vote_tokens_allowed(&payments);
         self.commit_vote_payments(voter, &proposal, &payments);
 
-        if self.is_plugged() {
-            let token = self.get_gov_token_option();
-            let plug = self.plug_contract().get();
-
-            self.tx()
-                .legacy_proxy_call(self.plug_proxy(plug).get_dao_vote_weight_view(&caller, OptionalValue::from(token)))
-                .callback(GovernanceModule::callbacks(self).vote_async_callback(voter, payment_weight, proposal.id, VoteType::Against, option_id))
-                .async_call_and_exit();
+        if self.request_plug_weights(&caller, voter, proposal.id, Option::Some(VoteType::Against), option_id, payment_weight.clone()) {
+            return;
         }
 
         self.vote(voter, proposal, VoteType::Against, payment_weight, option_id);
     }
 
-    /// Vote for or against a proposal via an asynchronous callback.
-    /// The callback result must return the original caller's vote weight.
-    /// Used majorly via the plugging feature.
-    #[callback]
-    fn vote_async_callback(
+    /// Abstain from a proposal. Abstentions count toward the quorum but not toward the for/against ratio.
+    /// Payment (optional):
+    ///     - token id must be equal to configured governance token id
+    ///     - amount must be greater than the min_vote_weight
+    ///     - ESDTs and SFTs will be deposited and locked until the voting period has ended
+    ///     - NFTs will be recorded as a vote and immediately returned
+    #[payable("*")]
+    #[endpoint(voteAbstain)]
+    fn vote_abstain_endpoint(&self, proposal: ProposalId, opt_option: OptionalValue<ProposalOptionId>) {
+        let caller = self.blockchain().get_caller();
+        let voter = self.users().get_or_create_user(&caller);
+        let option_id = opt_option.into_option().unwrap_or_default();
+        let payments = self.call_value().all_esdt_transfers();
+        let proposal = self.proposals(proposal).get();
+        let payment_weight = self.get_vote_weight_from_payments(&payments, voter, Option::Some(proposal.id));
+
+        self.require_payments_with_gov_token(&payments);
+        self.require_vote_tokens_allowed(&payments);
+        self.commit_vote_payments(voter, &proposal, &payments);
+
+        if self.request_plug_weights(&caller, voter, proposal.id, Option::Some(VoteType::Abstain), option_id, payment_weight.clone()) {
+            return;
+        }
+
+        self.vote(voter, proposal, VoteType::Abstain, payment_weight, option_id);
+    }
+
+    /// Queries the vote weight of the user from every plug not yet used by the user on the proposal.
+    /// The vote is cast, or the proposal activated, once all plugs have responded.
+    /// Returns false if no plug was queried.
+    fn request_plug_weights(
         &self,
-        voter: UserId,
-        payment_weight: BigUint,
+        address: &ManagedAddress,
+        user: UserId,
         proposal: ProposalId,
-        vote_type: VoteType,
-        option_id: u8,
-        #[call_result] result: ManagedAsyncCallResult<BigUint>,
-    ) {
-        match result {
-            ManagedAsyncCallResult::Ok(vote_weight) => {
-                let total_weight = if self.has_user_plug_voted(proposal, voter) {
-                    payment_weight
-                } else {
-                    &payment_weight + &vote_weight
-                };
+        vote_type: Option<VoteType>,
+        option_id: ProposalOptionId,
+        payment_weight: BigUint,
+    ) -> bool {
+        let plugs = self
+            .plugs()
+            .keys()
+            .filter(|plug| !self.has_user_plug_voted(proposal, user, plug))
+            .collect::<ManagedVec<ManagedAddress>>();
+
+        if plugs.is_empty() {
+            return false;
+        }
 
-                require!(total_weight > 0, "can not vote with 0 weight");
-                let proposal = self.proposals(proposal).get();
+        let token = self.get_gov_token_option();
+        let request_id = self.next_plug_request_id().update(|id| {
+            *id += 1;
+            *id
+        });
+
+        self.plug_requests(request_id).set(PlugWeightRequest {
+            proposal,
+            user,
+            vote_type,
+            option_id,
+            payment_weight,
+            plug_weight: BigUint::zero(),
+            counted_plugs: ManagedVec::new(),
+            pending: plugs.len(),
+            aborted: false,
+        });
+
+        for plug in plugs.iter() {
+            self.tx()
+                .legacy_proxy_call(self.plug_proxy(plug.clone_value()).get_dao_vote_weight_view(address, OptionalValue::from(token.clone())))
+                .gas(GAS_LIMIT_PLUG_WEIGHT)
+                .callback(GovernanceModule::callbacks(self).plug_weight_callback(request_id, plug.clone_value()))
+                .gas_for_callback(GAS_LIMIT_PLUG_WEIGHT_CALLBACK)
+                .register_promise();
+        }
+
+        true
+    }
 
-                if self.is_plugged() {
-                    self.record_plug_vote(voter, proposal.id);
+    /// Collects the vote weight of a single plug according to its failure policy.
+    /// The callback of the last responding plug casts the vote or activates the proposal.
+    #[promises_callback]
+    fn plug_weight_callback(&self, request_id: u64, plug: ManagedAddress, #[call_result] result: ManagedAsyncCallResult<BigUint>) {
+        let mut request = self.plug_requests(request_id).get();
+        request.pending -= 1;
+
+        // plugs removed in the meantime are not counted
+        if let Some(settings) = self.plugs().get(&plug) {
+            match result {
+                ManagedAsyncCallResult::Ok(weight) => {
+                    request.plug_weight += self.normalize_plug_weight(&settings, weight);
+                    request.counted_plugs.push(plug);
                 }
+                ManagedAsyncCallResult::Err(_) => match settings.failure_policy {
+                    PlugFailurePolicy::Ignore => {}
+                    PlugFailurePolicy::Zero => request.counted_plugs.push(plug),
+                    PlugFailurePolicy::Abort => {
+                        request.aborted = true;
+                        self.plug_failed_event(plug, request.proposal);
+                    }
+                },
+            }
+        }
+
+        if request.pending > 0 {
+            self.plug_requests(request_id).set(request);
+            return;
+        }
+
+        self.plug_requests(request_id).clear();
+
+        let proposal = self.proposals(request.proposal).get();
+
+        // payments of an aborted vote stay locked and are withdrawable like any vote once the proposal ended
+        if request.aborted {
+            match request.vote_type {
+                Some(_) => self.vote_abort_event(self.users().get_user_address_unchecked(request.user), request.proposal),
+                None => self.cancel_proposal(proposal),
+            }
+
+            return;
+        }
+
+        for plug in request.counted_plugs.iter() {
+            self.record_plug_vote(request.user, request.proposal, &plug);
+        }
 
-                self.vote(voter.clone(), proposal, vote_type, total_weight, option_id);
+        let total_weight = &request.payment_weight + &request.plug_weight;
+
+        match request.vote_type {
+            Some(vote_type) => {
+                require!(total_weight > 0, "can not vote with 0 weight");
+                self.vote(request.user, proposal, vote_type, total_weight, request.option_id);
             }
-            ManagedAsyncCallResult::Err(_) => {
-                sc_panic!("failed to retrieve caller vote weight");
+            None => {
+                if total_weight >= self.min_propose_weight().get() {
+                    self.activate_proposal(request.user, &proposal, &request.plug_weight, request.option_id);
+                } else {
+                    self.cancel_proposal(proposal);
+                }
             }
-        };
+        }
+    }
+
+    /// Change a previously cast vote to another vote type and, optionally, poll option while the proposal is active.
+    /// The full weight of the vote is moved between the tallies. No payment is accepted.
+    #[endpoint(changeVote)]
+    fn change_vote_endpoint(&self, proposal: ProposalId, vote_type: VoteType, opt_option_id: OptionalValue<ProposalOptionId>) {
+        let caller = self.blockchain().get_caller();
+        let voter = self.users().get_user_id(&caller);
+        require!(voter != 0, "no vote to change");
+
+        let option_id = opt_option_id.into_option().unwrap_or_default();
+        let proposal = self.get_proposal_or_fail(proposal);
+
+        self.change_vote(voter, proposal, vote_type, option_id);
     }
 
     /// Sign a proposal, optionally with a poll option.
@@ -304,25 +493,41 @@ pub trait GovernanceModule:
         self.sign(proposal, option_id);
     }
 
+    /// Start the execution delay of a proposal whose policies got fulfilled without a signature,
+    /// e.g. after role members have been unassigned. Usable by anyone.
+    #[endpoint(queueProposal)]
+    fn queue_proposal_endpoint(&self, proposal: ProposalId) {
+        let proposal = self.get_proposal_or_fail(proposal);
+        require!(self.proposal_succeeded_at(proposal.id).is_empty(), "proposal already queued");
+
+        self.record_proposal_success(&proposal);
+        require!(!self.proposal_succeeded_at(proposal.id).is_empty(), "proposal has not succeeded");
+    }
+
     /// Execute the actions of a succeeded proposal.
+    /// Proposals are queued and can not be executed until the execution delay has passed.
     /// This will update the proposals status to 'executed'.
     #[endpoint(execute)]
     fn execute_endpoint(&self, proposal: ProposalId, actions: MultiValueManagedVec<Action<Self::Api>>) {
         require!(!actions.is_empty(), "no actions to execute");
         require!(!self.proposals(proposal).is_empty(), "proposal not found");
 
-        self.persist_proposal_results();
-
         let actions = actions.into_vec();
         let actions_hash = self.calculate_actions_hash(&actions);
         let mut proposal = self.proposals(proposal).get();
         require!(proposal.actions_hash == actions_hash, "actions have been corrupted");
         require!(!proposal.executed, "proposal has already been executed");
+        self.require_actions_activate_committed_code(&proposal, &actions);
+
+        let status = self.get_proposal_status(&proposal);
+        require!(status != ProposalStatus::Queued, "proposal is queued for execution");
+        require!(status != ProposalStatus::Expired, "proposal has expired");
+        require!(status != ProposalStatus::Vetoed, "proposal has been vetoed");
 
-        let has_member_approval = self.get_proposal_status(&proposal) == ProposalStatus::Succeeded;
+        let has_member_approval = status == ProposalStatus::Succeeded;
         let (allowed, permissions) = self.get_actions_execute_info(proposal.proposer, &actions, has_member_approval);
         require!(allowed, "no permission for action");
-        require!(proposal.permissions == permissions, "untruthful permissions announced");
+        require!(self.are_permissions_announced(proposal.id, &permissions), "untruthful permissions announced");
 
         proposal.executed = true;
         self.proposals(proposal.id).set(&proposal);
@@ -337,8 +542,6 @@ pub trait GovernanceModule:
     fn direct_execute_endpoint(&self, actions: MultiValueManagedVec<Action<Self::Api>>) {
         require!(!actions.is_empty(), "no actions to execute");
 
-        self.persist_proposal_results();
-
         let caller = self.blockchain().get_caller();
         let user = self.users().get_or_create_user(&caller);
         let actions = actions.into_vec();
@@ -362,8 +565,21 @@ pub trait GovernanceModule:
         require!(proposal.proposer == caller, "proposer must cancel proposal");
 
         self.cancel_proposal(proposal);
+    }
+
+    /// Veto a succeeded proposal that has not been executed yet.
+    /// Can only be called by users with the guardian role.
+    /// Guardians can only be assigned and unassigned through proposals.
+    /// Arguments:
+    ///     - reason_hash: the hash of the reason for the veto to verify integrity on the frontend
+    #[endpoint(veto)]
+    fn veto_endpoint(&self, proposal: ProposalId, reason_hash: ManagedBuffer) {
+        self.require_caller_has_guardian_role();
+        require!(!reason_hash.is_empty(), "reason hash must not be empty");
+
+        let proposal = self.get_proposal_or_fail(proposal);
 
-        self.persist_proposal_results();
+        self.veto_proposal(proposal, reason_hash);
     }
 
     /// Withdraw locked governance tokens once the proposals voting period has ended.
@@ -418,45 +634,55 @@ pub trait GovernanceModule:
     }
 
     #[view(getProposalVotes)]
-    fn get_proposal_votes_view(&self, proposal: ProposalId) -> MultiValue2<BigUint, BigUint> {
+    fn get_proposal_votes_view(&self, proposal: ProposalId) -> MultiValue3<BigUint, BigUint, BigUint> {
         self.require_proposal_exists(proposal);
 
         let member_role = ManagedBuffer::from(ROLE_BUILTIN_MEMBER);
         let role = self.proposal_role_info(proposal, &member_role).get();
 
-        (role.votes_for, role.votes_against).into()
+        (role.votes_for, role.votes_against, role.votes_abstain).into()
+    }
+
+    #[view(getVote)]
+    fn get_vote_view(&self, proposal: ProposalId, address: ManagedAddress) -> OptionalValue<VoteReceipt<Self::Api>> {
+        let voter = self.users().get_user_id(&address);
+        let receipt_mapper = self.vote_receipts(proposal, voter);
+
+        if voter == 0 || receipt_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(receipt_mapper.get())
     }
 
     #[view(getProposalSigners)]
     fn get_proposal_signers_view(&self, proposal: ProposalId) -> MultiValueEncoded<ManagedAddress> {
-        let proposal = self.proposals(proposal).get();
-        let mut signers = MultiValueEncoded::new();
+        let proposal = self.get_proposal_or_fail(proposal);
+        let mut signers = ManagedVec::<Self::Api, ManagedAddress>::new();
 
-        // TODO: iterate over roles allowed to sign
-        // for role in proposer_roles.iter() {
-        //     for signer_id in self.proposal_signers(proposal.id, &role).iter() {
-        //         let address = self.users().get_user_address_unchecked(signer_id);
-        //         if !signers.to_vec().contains(&address) {
-        //             signers.push(address);
-        //         }
-        //     }
-        // }
+        for role in proposal.roles.iter() {
+            for signer_id in self.proposal_signers(proposal.id, &role).iter() {
+                let address = self.users().get_user_address_unchecked(signer_id);
+                if !signers.contains(&address) {
+                    signers.push(address);
+                }
+            }
+        }
 
-        signers
+        signers.into()
     }
 
     #[view(getProposalSignatureRoleCounts)]
     fn get_proposal_signature_role_counts_view(&self, proposal: ProposalId) -> MultiValueEncoded<MultiValue2<ManagedBuffer, usize>> {
-        let proposal = self.proposals(proposal).get();
+        let proposal = self.get_proposal_or_fail(proposal);
         let mut signers = MultiValueEncoded::new();
 
-        // TODO: iterate over roles allowed to sign
-        // for role in proposer_roles.iter() {
-        //     let signer_count = self.proposal_signers(proposal.id, &role).len();
-        //     if signer_count > 0 {
-        //         signers.push((role, signer_count).into());
-        //     }
-        // }
+        for role in proposal.roles.iter() {
+            let signer_count = self.proposal_signers(proposal.id, &role).len();
+            if signer_count > 0 {
+                signers.push((role.clone_value(), signer_count).into());
+            }
+        }
 
         signers
     }
@@ -473,7 +699,9 @@ pub trait GovernanceModule:
     }
 
     fn get_vote_weight_from_payments(&self, payments: &ManagedVec<EsdtTokenPayment>, voter: UserId, opt_proposal: Option<ProposalId>,) -> BigUint {
-        let mut total_weight = payments.into_iter().fold(BigUint::zero(), |carry, payment| carry + &payment.amount);
+        let mut total_weight = payments
+            .into_iter()
+            .fold(BigUint::zero(), |carry, payment| carry + self.get_gov_token_payment_weight(&payment));
 
         if opt_proposal.is_none() {
             return total_weight;
@@ -483,7 +711,7 @@ pub trait GovernanceModule:
 
         for locked_vote in self.locked_votes(proposal, voter).iter() {
             if !locked_vote.used.contains(&proposal) {
-                total_weight += &locked_vote.payment.amount;
+                total_weight += self.get_gov_token_payment_weight(&locked_vote.payment);
             }
         }
 
@@ -508,7 +736,7 @@ pub trait GovernanceModule:
 
             locked_vote.unlocks_at = new_unlocks_at;
             locked_vote.used.push(proposal.id);
-            self.locked_votes(proposal.id, user).set(i, &locked_vote);
+            self.locked_votes(proposal.id, user).set(i + 1, &locked_vote);
         }
 
         for payment in payments.into_iter() {
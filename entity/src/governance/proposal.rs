@@ -3,17 +3,23 @@ multiversx_sc::derive_imports!();
 
 use multiversx_sc::api::KECCAK256_RESULT_LEN;
 
+use super::delegation;
+use super::escrow;
 use super::events;
-use crate::config::{self, ProposalOptionId, ProposalRole, Timestamp, UserId, GAS_LIMIT_WITHDRAW, POLL_MAX_OPTIONS};
+use super::staking;
+use crate::config::{
+    self, ProposalOptionId, ProposalRole, Timestamp, UserId, APPROVAL_THRESHOLD_MAX, GAS_LIMIT_WITHDRAW, POLL_MAX_OPTIONS, QUORUM_PERCENTAGE_MAX,
+};
 use crate::config::ProposalId;
 use crate::permission::{self, PermissionName, RoleName};
 use crate::errors::PROPOSAL_NOT_ACTIVE;
 use crate::permission::PermissionDetails;
-use crate::permission::{Policy, PolicyMethod, ROLE_BUILTIN_LEADER};
+use crate::permission::{Policy, PolicyMethod, QuorumMode};
 use crate::plug;
 use core::convert::TryFrom;
 
 static ACTION_HASH_FIELDS_SEPARATOR: &[u8] = b"|";
+static ACTIVATE_CONTRACT_ENDPOINT: &[u8] = b"activateContract";
 
 #[type_abi]
 #[derive(TopEncode, TopDecode)]
@@ -73,6 +79,9 @@ pub enum ProposalStatus {
     Succeeded,
     Executed,
     Canceled,
+    Queued,
+    Vetoed,
+    Expired,
 }
 
 #[type_abi]
@@ -80,12 +89,47 @@ pub enum ProposalStatus {
 pub enum VoteType {
     For = 1,
     Against = 2,
+    Abstain = 3,
 }
 
-type IsFirstTimeSignature = bool;
+impl VoteType {
+    pub fn counts_toward_quorum(&self) -> bool {
+        matches!(self, VoteType::For | VoteType::Abstain)
+    }
+}
+
+/// The weight a vote applies to the tally of a role.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct RoleVoteWeight<M: ManagedTypeApi> {
+    pub role: RoleName<M>,
+    pub weight: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct VoteReceipt<M: ManagedTypeApi> {
+    pub vote_type: VoteType,
+    /// The weight committed in total.
+    pub weight: BigUint<M>,
+    pub option_id: ProposalOptionId,
+    pub voted_at: Timestamp,
+    /// The weight applied to each credited role, which is the square root of the committed weight under a quadratic policy.
+    pub roles: ManagedVec<M, RoleVoteWeight<M>>,
+    /// The weight applied to the poll option.
+    pub poll_weight: BigUint<M>,
+}
 
 #[multiversx_sc::module]
-pub trait ProposalModule: config::ConfigModule + permission::PermissionModule + events::GovEventsModule + plug::PlugModule {
+pub trait ProposalModule:
+    config::ConfigModule
+    + permission::PermissionModule
+    + events::GovEventsModule
+    + plug::PlugModule
+    + staking::StakingModule
+    + escrow::EscrowModule
+    + delegation::DelegationModule
+{
     fn create_proposal(
         &self,
         proposer: UserId,
@@ -97,10 +141,11 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         starts_at: Timestamp,
         vote_weight: BigUint,
         roles: ManagedVec<RoleName<Self::Api>>,
+        commitment: ManagedBuffer,
     ) -> Proposal<Self::Api> {
         let proposal_id = self.next_proposal_id().get();
 
-        self.require_proposed_via_trusted_host(proposer, &trusted_host_id, &content_hash, content_sig, &actions_hash, &roles);
+        self.require_proposed_via_trusted_host(proposer, &trusted_host_id, &content_hash, content_sig, &actions_hash, &roles, &commitment);
         require!(!self.known_trusted_host_proposal_ids().contains(&trusted_host_id), "proposal already registered");
 
         let (allowed, policies) = self.can_propose(proposer, &actions_hash, &roles);
@@ -108,7 +153,8 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         let has_weighted_policy = self.has_token_weighted_policy(&policies);
 
-        if has_weighted_policy {
+        // plug weight is collected asynchronously and checked once all plugs have responded
+        if has_weighted_policy && !self.is_plugged() {
             require!(vote_weight >= self.min_propose_weight().get(), "insufficient vote weight");
         }
 
@@ -130,7 +176,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             current_time
         };
 
-        require!(starts_at > current_time, "start time must be in the future"); // TODO: test
+        require!(starts_at >= current_time, "start time must not be in the past");
 
         let ends_at = starts_at + voting_period_minutes as u64 * 60;
 
@@ -155,22 +201,36 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             token: self.get_gov_token_option(),
             plug: self.get_plug_option(),
             quorum: self.default_quorum().get(),
-            permissions: ManagedVec::new(), // TODO
+            permissions: self.get_roles_policy_permissions(&proposal.roles),
         };
 
-        for role in proposal.roles.iter() {
-            if has_weighted_policy {
-                self.vote_for_role(&proposal, &role, VoteType::For, &vote_weight);
-            }
+        self.proposals(proposal_id).set(&proposal);
+        self.proposal_details(proposal_id).set(&proposal_details);
+        self.snapshot_role_member_amounts(&proposal);
+        self.next_proposal_id().set(proposal_id + 1);
+
+        if !commitment.is_empty() {
+            self.proposal_code_hash(proposal_id).set(&commitment);
+        }
+
+        // the proposer's vote is recorded so that it can be changed and later votes are aggregated with it
+        if has_weighted_policy && vote_weight > 0 {
+            self.add_vote_weight(proposer, &proposal, VoteType::For, &vote_weight, option_id, &proposal.roles);
+        } else {
+            self.cast_poll_vote(proposal.id, option_id, vote_weight.clone());
+        }
 
+        for role in proposal.roles.iter() {
             self.proposal_signers(proposal.id, &role).insert(proposer);
         }
 
-        self.proposals(proposal_id).set(&proposal);
-        self.proposal_details(proposal_id).set(&proposal_details);
-        self.next_proposal_id().set(proposal_id + 1);
-        self.cast_poll_vote(proposal.id.clone(), option_id, vote_weight.clone());
         self.known_trusted_host_proposal_ids().insert(trusted_host_id);
+
+        if has_weighted_policy {
+            self.freeze_proposal_execution_limits(&proposal);
+        } else {
+            self.record_proposal_success(&proposal);
+        }
         self.emit_propose_event(proposer, &proposal, vote_weight, option_id);
 
         proposal
@@ -189,6 +249,27 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         self.emit_cancel_event(&proposal);
     }
 
+    /// Counts the plug weight of the proposer toward a proposal once all plugs have responded.
+    fn activate_proposal(&self, proposer: UserId, proposal: &Proposal<Self::Api>, plug_weight: &BigUint, option_id: ProposalOptionId) {
+        let (_, policies) = self.can_propose(proposer, &proposal.actions_hash, &proposal.roles);
+
+        if *plug_weight == 0 || !self.has_token_weighted_policy(&policies) {
+            return;
+        }
+
+        self.add_vote_weight(proposer, proposal, VoteType::For, plug_weight, option_id, &proposal.roles);
+    }
+
+    fn veto_proposal(&self, proposal: Proposal<Self::Api>, reason_hash: ManagedBuffer) {
+        let status = self.get_proposal_status(&proposal);
+
+        require!(status == ProposalStatus::Succeeded || status == ProposalStatus::Queued, "proposal can not be vetoed");
+
+        self.proposal_veto_reason(proposal.id).set(&reason_hash);
+
+        self.emit_veto_event(&proposal, &reason_hash);
+    }
+
     fn get_proposal_status(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
         let current_time = self.blockchain().get_block_timestamp();
 
@@ -200,17 +281,21 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             return ProposalStatus::Executed;
         }
 
-        if proposal.starts_at < current_time{
+        if !self.proposal_veto_reason(proposal.id).is_empty() {
+            return ProposalStatus::Vetoed;
+        }
+
+        if current_time < proposal.starts_at {
             return ProposalStatus::Pending;
         }
 
-        let (meets_policy_requirements, has_weighted_policy) = self.are_policies_fulfilled(&proposal);
+        let (meets_policy_requirements, has_weighted_policy) = self.are_policies_fulfilled(proposal);
 
         // When policies are fulfilled, and no time-based policies like
         // token-weighted or plug-based are applied that depend on the
         // voting period to end, the proposal is considered successful.
         if meets_policy_requirements && !has_weighted_policy {
-            return ProposalStatus::Succeeded;
+            return self.get_succeeded_proposal_status(proposal, has_weighted_policy);
         }
 
         if current_time < proposal.ends_at {
@@ -218,15 +303,107 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         }
 
         if meets_policy_requirements {
-            return ProposalStatus::Succeeded;
+            return self.get_succeeded_proposal_status(proposal, has_weighted_policy);
         }
 
         ProposalStatus::Defeated
     }
 
+    /// Succeeded proposals stay queued until the execution delay has passed.
+    /// The delay starts when the voting period ends for token-weighted proposals,
+    /// or when the last required signature has been given otherwise.
+    /// Once executable, proposals expire if they are not executed within the execution window.
+    /// Signature-based proposals fulfilled without a signature, e.g. after role members got unassigned,
+    /// stay queued until `queueProposal` records their success, unless neither a delay nor a window applies.
+    fn get_succeeded_proposal_status(&self, proposal: &Proposal<Self::Api>, has_weighted_policy: bool) -> ProposalStatus {
+        let (execution_delay, execution_window) = self.get_proposal_execution_limits_seconds(proposal);
+
+        let succeeded_at = if has_weighted_policy {
+            proposal.ends_at
+        } else if !self.proposal_succeeded_at(proposal.id).is_empty() {
+            self.proposal_succeeded_at(proposal.id).get()
+        } else if execution_delay == 0 && execution_window == 0 {
+            return ProposalStatus::Succeeded;
+        } else {
+            return ProposalStatus::Queued;
+        };
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let executable_at = succeeded_at + execution_delay;
+
+        if current_time < executable_at {
+            return ProposalStatus::Queued;
+        }
+
+        if execution_window > 0 && current_time >= executable_at + execution_window {
+            return ProposalStatus::Expired;
+        }
+
+        ProposalStatus::Succeeded
+    }
+
+    /// Returns the execution delay and execution window in seconds for a proposal.
+    /// The limits are frozen once the proposal succeeds, so later configuration changes do not affect it.
+    fn get_proposal_execution_limits_seconds(&self, proposal: &Proposal<Self::Api>) -> (u64, u64) {
+        let frozen_limits = self.proposal_execution_limits(proposal.id);
+
+        if !frozen_limits.is_empty() {
+            return frozen_limits.get();
+        }
+
+        self.calculate_proposal_execution_limits_seconds(proposal)
+    }
+
+    /// The longest execution delay and the shortest execution window of the entity defaults
+    /// and all policies applied to the proposal are used. An execution window of zero never expires.
+    fn calculate_proposal_execution_limits_seconds(&self, proposal: &Proposal<Self::Api>) -> (u64, u64) {
+        let details = self.proposal_details(proposal.id).get();
+        let mut execution_delay_minutes = self.default_execution_delay_minutes().get();
+        let mut execution_window_minutes = self.default_execution_window_minutes().get();
+        let proposer_roles = self.get_active_user_roles(proposal.proposer);
+
+        for permission in details.permissions.iter() {
+            for role in proposer_roles.iter() {
+                if let Some(policy) = self.get_role_policy(&role, &permission) {
+                    execution_delay_minutes = execution_delay_minutes.max(policy.execution_delay_minutes);
+
+                    if policy.execution_window_minutes > 0 && (execution_window_minutes == 0 || policy.execution_window_minutes < execution_window_minutes) {
+                        execution_window_minutes = policy.execution_window_minutes;
+                    }
+                }
+            }
+        }
+
+        (execution_delay_minutes as u64 * 60, execution_window_minutes as u64 * 60)
+    }
+
+    /// Records the time at which the policies of a signature-based proposal got fulfilled
+    /// and freezes its execution limits.
+    fn record_proposal_success(&self, proposal: &Proposal<Self::Api>) {
+        if !self.proposal_succeeded_at(proposal.id).is_empty() {
+            return;
+        }
+
+        let (meets_policy_requirements, has_weighted_policy) = self.are_policies_fulfilled(proposal);
+
+        if meets_policy_requirements && !has_weighted_policy {
+            let current_time = self.blockchain().get_block_timestamp();
+            self.proposal_succeeded_at(proposal.id).set(current_time);
+            self.freeze_proposal_execution_limits(proposal);
+        }
+    }
+
+    /// Token-weighted proposals succeed when their voting period ends, which is known at creation,
+    /// so their limits are frozen right away. Signature-based proposals freeze them once fulfilled.
+    fn freeze_proposal_execution_limits(&self, proposal: &Proposal<Self::Api>) {
+        let limits = self.calculate_proposal_execution_limits_seconds(proposal);
+
+        self.proposal_execution_limits(proposal.id).set(limits);
+    }
+
     fn are_policies_fulfilled(&self, proposal: &Proposal<Self::Api>) -> (bool, bool) {
         let details = self.proposal_details(proposal.id).get();
-        let proposer_roles = self.user_roles(proposal.proposer);
+        let proposer_roles = self.get_active_user_roles(proposal.proposer);
 
         require!(!proposal.roles.is_empty(), "proposal has no defined roles");
         require!(!details.permissions.is_empty(), "proposal has no defined permissions");
@@ -237,28 +414,32 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         // Evaluating each permission against the proposer's roles and associated policies.
         for permission in details.permissions.iter() {
+            // A permission whose policies were only held through expired roles is no longer covered.
+            let mut has_policy = false;
+
             let is_fulfilled = proposer_roles
                 .iter()
-                .map(|role| {
-                    if let Some(policy) = self.policies(&role).get(&permission) {
-                        if policy.method == PolicyMethod::Weight {
+                .all(|role| {
+                    if let Some(policy) = self.get_role_policy(&role, &permission) {
+                        has_policy = true;
+
+                        if policy.method.is_token_weighted() {
                             has_weighted_policy = true;
                         }
 
                         match policy.method {
-                            PolicyMethod::Weight => self.has_sufficient_votes(&proposal, &role, &policy.quorum),
+                            PolicyMethod::Weight | PolicyMethod::Quadratic => self.has_sufficient_votes(proposal, &role, &policy),
                             PolicyMethod::One => self.proposal_signers(proposal.id, &role).contains(&proposal.proposer),
-                            PolicyMethod::All => self.proposal_signers(proposal.id, &role).len() >= self.roles_member_amount(&role).get(),
+                            PolicyMethod::All => self.proposal_signers(proposal.id, &role).len() >= self.get_proposal_role_member_amount(proposal.id, &role),
                             PolicyMethod::Quorum => BigUint::from(self.proposal_signers(proposal.id, &role).len()) >= policy.quorum,
-                            PolicyMethod::Majority => self.has_signer_majority_for_role(&proposal, &role),
+                            PolicyMethod::Majority => self.has_signer_majority_for_role(proposal, &role),
                         }
                     } else {
                         true
                     }
-                })
-                .all(|fulfilled| fulfilled == true);
+                });
 
-            if !is_fulfilled {
+            if !is_fulfilled || !has_policy {
                 are_fulfilled_all = false;
             }
         }
@@ -267,7 +448,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn execute_actions(&self, actions: &ManagedVec<Action<Self::Api>>) {
-        self.ensure_tokens_available_for_actions(&actions);
+        self.ensure_tokens_available_for_actions(actions);
 
         for action in actions.iter() {
             let call = self.tx().to(action.destination).raw_call(action.endpoint).arguments_raw(action.arguments.into()).gas(action.gas_limit);
@@ -281,33 +462,33 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn ensure_tokens_available_for_actions(&self, actions: &ManagedVec<Action<Self::Api>>) {
-        let mut token_map = ManagedMap::new();
+        let mut totals: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
 
         for action in actions.iter() {
             for payment in action.payments.iter() {
-                let mut key = ManagedBuffer::new();
-                key.append(payment.token_identifier.as_managed_buffer());
-                key.append_bytes(&payment.token_nonce.to_be_bytes());
-
-                if !token_map.contains(&key) {
-                    let val = payment.amount.to_bytes_be_buffer();
-
-                    // token_map.put(&key, val); // TODO
+                let existing = totals
+                    .iter()
+                    .position(|total| total.token_identifier == payment.token_identifier && total.token_nonce == payment.token_nonce);
+
+                match existing {
+                    Some(index) => {
+                        let mut total = totals.get(index);
+                        total.amount += &payment.amount;
+                        let _ = totals.set(index, &total);
+                    }
+                    None => totals.push(payment.clone()),
                 }
-
-                // let current = token_map.get(key.into());
-                // let new = current + &payment.amount;
-                // token_map.put(key, new);
             }
         }
 
         // Check if tokens are available for all accumulated payments
-        for (key, amount) in token_map.iter() {
-            self.require_tokens_available(&key.0, amount, key.1);
+        for total in totals.iter() {
+            self.require_tokens_available(&total.token_identifier, total.token_nonce, &total.amount);
         }
     }
 
     fn vote(&self, voter: UserId, proposal: Proposal<Self::Api>, vote_type: VoteType, weight: BigUint, option_id: u8) {
+        let weight = weight + self.take_deposited_weight(voter, &proposal);
         require!(weight > 0, "vote weight must be greater than 0");
 
         let min_vote_weight = self.min_vote_weight().get();
@@ -319,27 +500,268 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         let intersecting_roles = self.get_user_intersecting_proposal_roles_or_fail(voter, &proposal);
 
-        for role in intersecting_roles.iter() {
-            self.vote_for_role(&proposal, &role, vote_type.clone(), &weight);
+        self.add_vote_weight(voter, &proposal, vote_type.clone(), &weight, option_id, &intersecting_roles);
+        self.emit_vote_event(voter, &proposal, vote_type, weight, option_id);
+    }
+
+    /// Collects the weight of staked and escrowed governance tokens for a vote, evaluated right before the proposal started:
+    /// - on their first direct vote, users use their own weight and take their stake back from their delegate
+    /// - on their first vote, delegates use the stake delegated to them, except for delegators who have voted themselves
+    fn take_deposited_weight(&self, voter: UserId, proposal: &Proposal<Self::Api>) -> BigUint {
+        let mut weight = BigUint::zero();
+
+        if self.proposal_deposit_voters(proposal.id).insert(voter) {
+            let own_weight = self.get_deposited_weight_at(voter, proposal.starts_at);
+
+            self.withdraw_delegated_stake(voter, proposal);
+
+            if own_weight > 0 {
+                self.lock_deposited_weight_until(voter, proposal.ends_at);
+                weight += own_weight;
+            }
         }
 
-        self.cast_poll_vote(proposal.id, option_id, weight.clone());
-        self.emit_vote_event(voter, &proposal, vote_type, weight, option_id);
+        if self.proposal_delegate_voters(proposal.id).insert(voter) {
+            let delegated_stake = self.get_delegated_stake_at(voter, proposal.starts_at);
+            let excluded_stake = self.excluded_delegated_stakes(proposal.id).get(&voter).unwrap_or_default();
+
+            if delegated_stake > excluded_stake {
+                self.lock_delegated_stake_until(voter, proposal.ends_at);
+                weight += delegated_stake - excluded_stake;
+            }
+        }
+
+        weight
+    }
+
+    /// Takes the stake of a delegator who votes directly back from the delegate they had at the start of the proposal.
+    fn withdraw_delegated_stake(&self, delegator: UserId, proposal: &Proposal<Self::Api>) {
+        let delegate = self.get_delegate_at(delegator, proposal.starts_at);
+
+        if delegate == 0 {
+            return;
+        }
+
+        let stake = self.get_stake_at(delegator, proposal.starts_at);
+
+        if stake == 0 {
+            return;
+        }
+
+        if self.proposal_delegate_voters(proposal.id).contains(&delegate) {
+            self.revoke_delegated_stake(proposal, delegate, &stake);
+        } else {
+            let excluded_stake = self.excluded_delegated_stakes(proposal.id).get(&delegate).unwrap_or_default();
+            self.excluded_delegated_stakes(proposal.id).insert(delegate, excluded_stake + stake);
+        }
+    }
+
+    /// Returns the weight of governance tokens a user has staked or escrowed in the entity right before the given timestamp.
+    fn get_deposited_weight_at(&self, user: UserId, timestamp: Timestamp) -> BigUint {
+        self.get_stake_at(user, timestamp) + self.get_escrow_power_at(user, timestamp)
+    }
+
+    fn lock_deposited_weight_until(&self, user: UserId, until: Timestamp) {
+        self.lock_stake_until(user, until);
+        self.lock_escrow_votes_until(user, until);
+    }
+
+    /// Removes the stake a delegate used on behalf of a delegator from the delegate's vote.
+    fn revoke_delegated_stake(&self, proposal: &Proposal<Self::Api>, delegate: UserId, stake: &BigUint) {
+        let receipt_mapper = self.vote_receipts(proposal.id, delegate);
+        let mut receipt = receipt_mapper.get();
+        let remaining_weight = &receipt.weight - stake;
+
+        self.apply_vote_weight(proposal, &mut receipt, remaining_weight);
+        receipt_mapper.set(&receipt);
+    }
+
+    /// Moves the full weight of a previously cast vote to another vote type and poll option.
+    fn change_vote(&self, voter: UserId, proposal: Proposal<Self::Api>, vote_type: VoteType, option_id: ProposalOptionId) {
+        require!(self.get_proposal_status(&proposal) == ProposalStatus::Active, PROPOSAL_NOT_ACTIVE);
+
+        let receipt_mapper = self.vote_receipts(proposal.id, voter);
+        require!(!receipt_mapper.is_empty(), "no vote to change");
+
+        let mut receipt = receipt_mapper.get();
+        require!(receipt.vote_type != vote_type || receipt.option_id != option_id, "vote is unchanged");
+
+        for role_weight in receipt.roles.iter() {
+            self.unvote_for_role(&proposal, &role_weight.role, receipt.vote_type.clone(), &role_weight.weight);
+            self.vote_for_role(&proposal, &role_weight.role, vote_type.clone(), &role_weight.weight);
+
+            if receipt.vote_type.counts_toward_quorum() != vote_type.counts_toward_quorum() && self.is_quadratic_role(&proposal, &role_weight.role) {
+                self.proposal_role_quorum_weight(proposal.id, &role_weight.role).update(|quorum_weight| {
+                    if vote_type.counts_toward_quorum() {
+                        *quorum_weight += &receipt.weight;
+                    } else {
+                        *quorum_weight -= &receipt.weight;
+                    }
+                });
+            }
+        }
+
+        self.uncast_poll_vote(proposal.id, receipt.option_id, receipt.poll_weight.clone());
+        self.cast_poll_vote(proposal.id, option_id, receipt.poll_weight.clone());
+
+        receipt.vote_type = vote_type.clone();
+        receipt.option_id = option_id;
+        receipt.voted_at = self.blockchain().get_block_timestamp();
+        receipt_mapper.set(&receipt);
+
+        self.emit_vote_change_event(voter, &proposal, vote_type, receipt.weight, option_id);
+    }
+
+    /// Adds committed weight to the vote of a voter and applies it to the tallies of the credited roles and the poll.
+    /// Repeated votes add up, must keep the vote type and poll option and are credited to the roles of the first vote.
+    fn add_vote_weight(
+        &self,
+        voter: UserId,
+        proposal: &Proposal<Self::Api>,
+        vote_type: VoteType,
+        weight: &BigUint,
+        option_id: ProposalOptionId,
+        roles: &ManagedVec<RoleName<Self::Api>>,
+    ) {
+        let receipt_mapper = self.vote_receipts(proposal.id, voter);
+
+        let mut receipt = if receipt_mapper.is_empty() {
+            let mut role_weights = ManagedVec::new();
+
+            for role in roles.iter() {
+                role_weights.push(RoleVoteWeight {
+                    role: role.clone_value(),
+                    weight: BigUint::zero(),
+                });
+            }
+
+            VoteReceipt {
+                vote_type,
+                weight: BigUint::zero(),
+                option_id,
+                voted_at: 0,
+                roles: role_weights,
+                poll_weight: BigUint::zero(),
+            }
+        } else {
+            let receipt = receipt_mapper.get();
+            require!(receipt.vote_type == vote_type, "already voted with another vote type");
+            require!(receipt.option_id == option_id, "already voted for another poll option");
+
+            receipt
+        };
+
+        let total_weight = &receipt.weight + weight;
+
+        self.apply_vote_weight(proposal, &mut receipt, total_weight);
+        receipt.voted_at = self.blockchain().get_block_timestamp();
+        receipt_mapper.set(&receipt);
+
+        self.proposal_voters(proposal.id).insert(voter);
+        self.record_proposal_voting_power(proposal);
+    }
+
+    /// Changes the committed weight of a vote and moves the tallies of the credited roles and the poll by the change of their applied weight.
+    fn apply_vote_weight(&self, proposal: &Proposal<Self::Api>, receipt: &mut VoteReceipt<Self::Api>, committed_weight: BigUint) {
+        let mut role_weights = ManagedVec::new();
+
+        for role_weight in receipt.roles.iter() {
+            let weight = self.get_role_vote_weight(proposal, &role_weight.role, &committed_weight);
+
+            if receipt.vote_type.counts_toward_quorum() && self.is_quadratic_role(proposal, &role_weight.role) {
+                self.proposal_role_quorum_weight(proposal.id, &role_weight.role).update(|quorum_weight| {
+                    *quorum_weight += &committed_weight;
+                    *quorum_weight -= &receipt.weight;
+                });
+            }
+
+            if weight > role_weight.weight {
+                self.vote_for_role(proposal, &role_weight.role, receipt.vote_type.clone(), &(&weight - &role_weight.weight));
+            } else {
+                self.unvote_for_role(proposal, &role_weight.role, receipt.vote_type.clone(), &(&role_weight.weight - &weight));
+            }
+
+            role_weights.push(RoleVoteWeight {
+                role: role_weight.role.clone(),
+                weight,
+            });
+        }
+
+        let poll_weight = self.get_poll_vote_weight(proposal, &committed_weight);
+
+        if poll_weight > receipt.poll_weight {
+            self.cast_poll_vote(proposal.id, receipt.option_id, &poll_weight - &receipt.poll_weight);
+        } else {
+            self.uncast_poll_vote(proposal.id, receipt.option_id, &receipt.poll_weight - &poll_weight);
+        }
+
+        receipt.weight = committed_weight;
+        receipt.roles = role_weights;
+        receipt.poll_weight = poll_weight;
+    }
+
+    fn get_committed_vote_weight(&self, voter: UserId, proposal: ProposalId) -> BigUint {
+        let receipt_mapper = self.vote_receipts(proposal, voter);
+
+        if receipt_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        receipt_mapper.get().weight
+    }
+
+    /// Returns the weight that counts toward the tally of a role for the tokens a voter committed in total:
+    /// the integer square root if the role's policy for the proposal permissions is quadratic, otherwise the committed weight itself.
+    fn get_role_vote_weight(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>, committed_weight: &BigUint) -> BigUint {
+        if self.is_quadratic_role(proposal, role) {
+            committed_weight.sqrt()
+        } else {
+            committed_weight.clone()
+        }
+    }
+
+    /// Returns the weight that counts toward the poll for the tokens a voter committed in total:
+    /// the integer square root if any proposal role is under a quadratic policy, otherwise the committed weight itself.
+    fn get_poll_vote_weight(&self, proposal: &Proposal<Self::Api>, committed_weight: &BigUint) -> BigUint {
+        if proposal.roles.iter().any(|role| self.is_quadratic_role(proposal, &role)) {
+            committed_weight.sqrt()
+        } else {
+            committed_weight.clone()
+        }
+    }
+
+    /// Checks whether the policy of a role for any of the proposal permissions is quadratic.
+    fn is_quadratic_role(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>) -> bool {
+        let details = self.proposal_details(proposal.id).get();
+
+        details
+            .permissions
+            .iter()
+            .any(|permission| matches!(self.get_role_policy(role, &permission), Some(policy) if policy.method == PolicyMethod::Quadratic))
     }
 
     fn vote_for_role(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>, vote_type: VoteType, weight: &BigUint) {
-        let mut role_info = if self.proposal_role_info(proposal.id, &role).is_empty() {
+        let mut role_info = if self.proposal_role_info(proposal.id, role).is_empty() {
             ProposalRole::default()
         } else {
-            self.proposal_role_info(proposal.id, &role).get()
+            self.proposal_role_info(proposal.id, role).get()
         };
 
         match vote_type {
             VoteType::For => role_info.votes_for += weight,
             VoteType::Against => role_info.votes_against += weight,
+            VoteType::Abstain => role_info.votes_abstain += weight,
         }
 
-        self.proposal_role_info(proposal.id, &role).set(&role_info);
+        self.proposal_role_info(proposal.id, role).set(&role_info);
+    }
+
+    fn unvote_for_role(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>, vote_type: VoteType, weight: &BigUint) {
+        self.proposal_role_info(proposal.id, role).update(|role_info| match vote_type {
+            VoteType::For => role_info.votes_for -= weight,
+            VoteType::Against => role_info.votes_against -= weight,
+            VoteType::Abstain => role_info.votes_abstain -= weight,
+        });
     }
 
     // TODO: test only signs for roles that are intersecting with the proposal
@@ -361,6 +783,8 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             }
         }
 
+        self.record_proposal_success(&proposal);
+
         // TODO: add test that checks can only sign once
         if is_first_time_sig {
             self.cast_poll_vote(proposal.id, option, BigUint::from(1u8));
@@ -369,8 +793,12 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn get_user_intersecting_proposal_roles_or_fail(&self, user: UserId, proposal: &Proposal<Self::Api>) -> ManagedVec<RoleName<Self::Api>> {
-        let user_roles = self.user_roles(user);
-        let intersecting_roles = proposal.roles.iter().filter(|role| user_roles.contains(role)).collect();
+        let intersecting_roles = proposal
+            .roles
+            .iter()
+            .filter(|role| self.has_user_role(user, role))
+            .map(|role| role.clone_value())
+            .collect::<ManagedVec<RoleName<Self::Api>>>();
 
         require!(!intersecting_roles.is_empty(), "user does not have required roles");
 
@@ -389,6 +817,14 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         self.proposal_poll(proposal, option).update(|current| *current += weight);
     }
 
+    fn uncast_poll_vote(&self, proposal: ProposalId, option: ProposalOptionId, weight: BigUint) {
+        if option == 0 || weight == 0 {
+            return;
+        }
+
+        self.proposal_poll(proposal, option).update(|current| *current -= weight);
+    }
+
     fn withdraw_proposal_votes(&self, proposal: ProposalId) {
         let mut voter_ids_mapper = self.locked_voters(proposal);
         let safe_voter_ids = voter_ids_mapper.iter().collect::<ManagedVec<UserId>>();
@@ -474,12 +910,33 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         require!(status != ProposalStatus::Pending, "proposal is still pending");
         require!(status != ProposalStatus::Active, "proposal is still active");
+        require!(status != ProposalStatus::Queued, "proposal is still queued");
 
         // cleanup
         self.proposals(proposal.id).clear();
         self.proposal_details(proposal.id).clear();
+        self.proposal_role_member_amounts(proposal.id).clear();
+        self.proposal_succeeded_at(proposal.id).clear();
+        self.proposal_execution_limits(proposal.id).clear();
+        self.proposal_voting_power(proposal.id).clear();
+        self.proposal_veto_reason(proposal.id).clear();
         self.proposal_nft_votes(proposal.id).clear();
-        self.plug_votes(proposal.id).clear();
+
+        for plug in self.proposal_plugs(proposal.id).iter() {
+            self.plug_votes(proposal.id, &plug).clear();
+        }
+
+        self.proposal_plugs(proposal.id).clear();
+        self.legacy_plug_votes(proposal.id).clear();
+
+        for voter in self.proposal_voters(proposal.id).iter() {
+            self.vote_receipts(proposal.id, voter).clear();
+        }
+
+        self.proposal_voters(proposal.id).clear();
+        self.proposal_deposit_voters(proposal.id).clear();
+        self.proposal_delegate_voters(proposal.id).clear();
+        self.excluded_delegated_stakes(proposal.id).clear();
 
         for option in 1..=POLL_MAX_OPTIONS {
             self.proposal_poll(proposal.id, option).clear();
@@ -487,6 +944,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         for role in proposal.roles.iter() {
             self.proposal_role_info(proposal.id, &role).clear();
+            self.proposal_role_quorum_weight(proposal.id, &role).clear();
             self.proposal_signers(proposal.id, &role).clear();
         }
 
@@ -502,12 +960,20 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     fn can_propose(&self, proposer: UserId, actions_hash: &ManagedBuffer, policy_ids: &ManagedVec<RoleName<Self::Api>>) -> (bool, ManagedVec<Policy<Self::Api>>) {
         let has_actions = actions_hash.is_empty(); // no actions -> always allowed
 
-        let policies = policy_ids.iter()
-            .map(|policy_id| self.policies(policy_id).get())
-            .collect::<ManagedVec<Policy<Self::Api>>>();
+        let mut policies = ManagedVec::new();
+        let mut has_proposer_any_role = false;
 
-        let has_proposer_any_role = policies.iter()
-            .any(|policy| self.user_roles(proposer).contains(&policy.role));
+        for role in policy_ids.iter() {
+            for permission in self.get_role_policy_permissions(&role).iter() {
+                if let Some(policy) = self.get_role_policy(&role, &permission) {
+                    policies.push(policy);
+                }
+            }
+
+            if self.has_user_role(proposer, &role) {
+                has_proposer_any_role = true;
+            }
+        }
 
         let allowed = has_proposer_any_role || self.is_leaderless() || !has_actions;
 
@@ -541,6 +1007,27 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         self.crypto().keccak256(&serialized).as_managed_buffer().clone()
     }
 
+    /// Requires the actions of a proposal that commits to staged contract code to activate exactly that code.
+    /// The code hash is passed as an argument of `activateContract`, so it is covered by the actions hash.
+    fn require_actions_activate_committed_code(&self, proposal: &Proposal<Self::Api>, actions: &ManagedVec<Action<Self::Api>>) {
+        let code_hash_mapper = self.proposal_code_hash(proposal.id);
+
+        if code_hash_mapper.is_empty() {
+            return;
+        }
+
+        let code_hash = code_hash_mapper.get();
+        let entity = self.blockchain().get_sc_address();
+
+        let activates_code = actions.iter().any(|action| {
+            action.destination == entity
+                && action.endpoint == *ACTIVATE_CONTRACT_ENDPOINT
+                && action.arguments.try_get(2).map(|arg| *arg == code_hash).unwrap_or(false)
+        });
+
+        require!(activates_code, "actions must activate the approved code");
+    }
+
     // Note: used on execute and direct execute
     fn get_actions_execute_info(
         &self,
@@ -548,19 +1035,20 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         actions: &ManagedVec<Action<Self::Api>>,
         has_member_approval: bool,
     ) -> (bool, ManagedVec<ManagedBuffer>) {
-        let proposer_roles = self.user_roles(user);
+        let proposer_roles = self.get_active_user_roles(user);
         let mut applied_permissions = ManagedVec::new();
 
         for action in actions.iter() {
             let mut has_permission_for_action = false;
 
             for role in proposer_roles.iter() {
-                for (permission, policy) in self.policies(&role).iter() {
+                for permission in self.get_role_policy_permissions(&role).iter() {
+                    let policy = self.get_role_policy(&role, &permission).unwrap_or_else(|| sc_panic!("policy does not exist"));
                     let permission_details = self.permission_details(&permission).get();
 
                     if self.does_permission_apply_to_action(&permission_details, &action) {
-                        applied_permissions.push(permission);
-                        has_permission_for_action = has_member_approval || policy.method == PolicyMethod::One;
+                        applied_permissions.push(permission.clone_value());
+                        has_permission_for_action = has_member_approval || self.is_policy_fulfilled_unilaterally(&role, &policy);
                     }
                 }
             }
@@ -574,20 +1062,42 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         (true, applied_permissions)
     }
 
+    /// Checks whether a single role member can fulfill a policy on their own,
+    /// e.g. the only leader of an entity under a majority policy.
+    /// Policies with an execution delay always require a proposal.
+    fn is_policy_fulfilled_unilaterally(&self, role: &RoleName<Self::Api>, policy: &Policy<Self::Api>) -> bool {
+        if policy.execution_delay_minutes > 0 || self.default_execution_delay_minutes().get() > 0 {
+            return false;
+        }
+
+        match policy.method {
+            PolicyMethod::One => true,
+            PolicyMethod::All | PolicyMethod::Majority => self.get_role_member_amount(role) <= 1,
+            PolicyMethod::Quorum => policy.quorum <= 1u64,
+            PolicyMethod::Weight | PolicyMethod::Quadratic => false,
+        }
+    }
+
     fn does_permission_apply_to_action(&self, permission_details: &PermissionDetails<Self::Api>, action: &Action<Self::Api>) -> bool {
         // check value/EGLD mismatch
-        if permission_details.value.is_some() && action.value > permission_details.value.unwrap() {
-            return false;
+        if let Some(value) = &permission_details.value {
+            if &action.value > value {
+                return false;
+            }
         }
 
         // check destination mismatch
-        if !permission_details.destination.is_zero() && action.destination != permission_details.destination {
-            return false;
+        if let Some(destination) = &permission_details.destination {
+            if &action.destination != destination {
+                return false;
+            }
         }
 
         // check endpoint mismatch
-        if !permission_details.endpoint.is_empty() && action.endpoint != permission_details.endpoint {
-            return false;
+        if let Some(endpoint) = &permission_details.endpoint {
+            if &action.endpoint != endpoint {
+                return false;
+            }
         }
 
         // check arguments mismatch. ignored if permission contains no arguments.
@@ -595,12 +1105,10 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         //      - passes: arg1, arg2 (permission) -> arg1, arg2, arg3 (action)
         //      - fails: arg1, arg2 (permission) -> arg1, arg3 (action)
         //      - fails: arg1, arg2 (permission) -> arg1 (action)
-        if !permission_details.args.is_empty() {
-            for (i, perm_arg) in permission_details.args.into_iter().enumerate() {
+        if let Some(arguments) = &permission_details.arguments {
+            for (i, perm_arg) in arguments.iter().enumerate() {
                 if let Option::Some(arg_at_index) = action.arguments.try_get(i).as_deref() {
-                    let applies = arg_at_index == &perm_arg;
-
-                    if applies {
+                    if *arg_at_index == *perm_arg {
                         continue;
                     }
                 }
@@ -611,9 +1119,9 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
 
         // check payments mismatch. ignored if permission contains no payments.
         // returns false, if a payment is not in the permissions or exceeds payment amount.
-        if !permission_details.payments.is_empty() {
-            let applies = action.payments.into_iter().all(|payment| {
-                if let Some(guard) = permission_details.payments.into_iter().find(|p| p.token_identifier == payment.token_identifier) {
+        if let Some(payments) = &permission_details.payments {
+            let applies = action.payments.iter().all(|payment| {
+                if let Some(guard) = payments.iter().find(|p| p.token_identifier == payment.token_identifier) {
                     payment.amount <= guard.amount
                 } else {
                     false
@@ -628,8 +1136,12 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         true
     }
 
-    fn has_sufficient_votes(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>, quorum: &BigUint) -> bool {
-        let proposal_role = self.proposal_role_info(proposal.id, &role).get();
+    fn has_sufficient_votes(&self, proposal: &Proposal<Self::Api>, role: &RoleName<Self::Api>, policy: &Policy<Self::Api>) -> bool {
+        if self.proposal_role_info(proposal.id, role).is_empty() {
+            return false;
+        }
+
+        let proposal_role = self.proposal_role_info(proposal.id, role).get();
 
         let total_votes = &proposal_role.votes_for + &proposal_role.votes_against;
 
@@ -637,19 +1149,129 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             return false;
         }
 
-        let vote_for_percent = &proposal_role.votes_for * &BigUint::from(100u64) / &total_votes;
-        let vote_for_percent_to_pass = BigUint::from(50u64);
+        let votes_for_bps = &proposal_role.votes_for * &BigUint::from(APPROVAL_THRESHOLD_MAX);
+        let votes_for_bps_to_pass = &total_votes * &BigUint::from(policy.approval_threshold);
+
+        // abstentions count toward the quorum but not toward the for/against ratio.
+        // quorums are set in token units, so quadratic policies compare them against the committed weight instead of its square roots.
+        let votes_toward_quorum = if policy.method == PolicyMethod::Quadratic {
+            self.proposal_role_quorum_weight(proposal.id, role).get()
+        } else {
+            &proposal_role.votes_for + &proposal_role.votes_abstain
+        };
+
+        votes_for_bps >= votes_for_bps_to_pass && votes_toward_quorum >= self.get_required_quorum(proposal, policy)
+    }
+
+    /// Percentage quorums fall back to the quorum fallback when the circulating voting power is unknown or zero.
+    fn get_required_quorum(&self, proposal: &Proposal<Self::Api>, policy: &Policy<Self::Api>) -> BigUint {
+        match policy.quorum_mode {
+            QuorumMode::Absolute => policy.quorum.clone(),
+            QuorumMode::Percentage => {
+                let voting_power = self.get_proposal_voting_power(proposal);
+
+                if voting_power == 0 {
+                    return self.quorum_fallback().get();
+                }
+
+                voting_power * &policy.quorum / QUORUM_PERCENTAGE_MAX
+            }
+        }
+    }
+
+    /// Returns the circulating voting power for the percentage quorum of a proposal.
+    /// Once voting has ended, the value recorded on the last vote is used, so later supply changes do not change the outcome.
+    fn get_proposal_voting_power(&self, proposal: &Proposal<Self::Api>) -> BigUint {
+        let recorded = self.proposal_voting_power(proposal.id);
+
+        if !recorded.is_empty() && self.blockchain().get_block_timestamp() >= proposal.ends_at {
+            return recorded.get();
+        }
+
+        self.get_circulating_voting_power().unwrap_or_default()
+    }
+
+    fn record_proposal_voting_power(&self, proposal: &Proposal<Self::Api>) {
+        if !self.has_percentage_quorum(proposal) {
+            return;
+        }
+
+        let voting_power = self.get_circulating_voting_power().unwrap_or_default();
+
+        self.proposal_voting_power(proposal.id).set(voting_power);
+    }
+
+    fn has_percentage_quorum(&self, proposal: &Proposal<Self::Api>) -> bool {
+        let details = self.proposal_details(proposal.id).get();
+
+        self.get_active_user_roles(proposal.proposer).iter().any(|role| {
+            details
+                .permissions
+                .iter()
+                .any(|permission| matches!(self.get_role_policy(&role, &permission), Some(policy) if policy.quorum_mode == QuorumMode::Percentage))
+        })
+    }
 
-        vote_for_percent >= vote_for_percent_to_pass && &proposal_role.votes_for >= quorum
+    /// Returns the voting power currently available to members through the governance token and the plugs,
+    /// or none if a plug does not report its total weight.
+    fn get_circulating_voting_power(&self) -> Option<BigUint> {
+        self.get_plug_total_weight().map(|plug_weight| self.get_circulating_gov_token_supply() + plug_weight)
     }
 
     fn has_signer_majority_for_role(&self, proposal: &Proposal<Self::Api>, role: &ManagedBuffer) -> bool {
-        let signer_count = self.proposal_signers(proposal.id, &role).len();
-        let signer_majority = self.roles_member_amount(&role).get() / 2 + 1;
+        let signer_count = self.proposal_signers(proposal.id, role).len();
+        let signer_majority = self.get_proposal_role_member_amount(proposal.id, role) / 2 + 1;
 
         signer_count > 0 && signer_count >= signer_majority
     }
 
+    /// Freezes the member amounts of the proposal's roles and the proposer's active roles,
+    /// so that later role assignments do not affect signer policies of the proposal.
+    fn snapshot_role_member_amounts(&self, proposal: &Proposal<Self::Api>) {
+        let mut snapshot = self.proposal_role_member_amounts(proposal.id);
+
+        for role in proposal.roles.iter() {
+            snapshot.insert(role.clone_value(), self.get_role_member_amount(&role));
+        }
+
+        for role in self.get_active_user_roles(proposal.proposer).iter() {
+            if !snapshot.contains_key(&role) {
+                let amount = self.get_role_member_amount(&role);
+                snapshot.insert(role.clone_value(), amount);
+            }
+        }
+    }
+
+    /// Returns the member amount of a role frozen at proposal creation,
+    /// or the live amount if the role was not frozen, like for proposals created before snapshots existed.
+    fn get_proposal_role_member_amount(&self, proposal: ProposalId, role: &RoleName<Self::Api>) -> usize {
+        self.proposal_role_member_amounts(proposal)
+            .get(role)
+            .unwrap_or_else(|| self.get_role_member_amount(role))
+    }
+
+    /// Returns the permissions for which any of the given roles defines or inherits a policy.
+    fn get_roles_policy_permissions(&self, roles: &ManagedVec<RoleName<Self::Api>>) -> ManagedVec<PermissionName<Self::Api>> {
+        let mut permissions = ManagedVec::<Self::Api, PermissionName<Self::Api>>::new();
+
+        for role in roles.iter() {
+            for permission in self.get_role_policy_permissions(&role).iter() {
+                if !permissions.contains(&permission) {
+                    permissions.push(permission.clone_value());
+                }
+            }
+        }
+
+        permissions
+    }
+
+    /// Checks whether the permissions applied to actions are covered by the permissions of the proposal.
+    fn are_permissions_announced(&self, proposal: ProposalId, applied_permissions: &ManagedVec<PermissionName<Self::Api>>) -> bool {
+        let announced = self.proposal_details(proposal).get().permissions;
+
+        applied_permissions.iter().all(|permission| announced.contains(&permission))
+    }
+
     fn get_proposal_or_fail(&self, proposal: ProposalId) -> Proposal<Self::Api> {
         self.require_proposal_exists(proposal);
 
@@ -668,6 +1290,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         content_sig: ManagedBuffer,
         actions_hash: &ManagedBuffer,
         roles: &ManagedVec<RoleName<Self::Api>>,
+        commitment: &ManagedBuffer,
     ) {
         let entity = self.blockchain().get_sc_address();
         let proposer = self.users().get_user_address_unchecked(proposer);
@@ -684,6 +1307,9 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             signable.append(&role);
         }
 
+        // additional data the proposal commits to, e.g. the hash of staged contract code
+        signable.append(commitment);
+
         self.require_signed_by_trusted_host(&signable, &trusted_host_sig);
     }
 }
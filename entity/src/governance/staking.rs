@@ -0,0 +1,251 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use crate::config::{self, Checkpoint, Timestamp, UserId, UNBONDING_PERIOD_MINUTES_MAX};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct StakeCheckpoint<M: ManagedTypeApi> {
+    pub timestamp: Timestamp,
+    pub amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Checkpoint for StakeCheckpoint<M> {
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+#[multiversx_sc::module]
+pub trait StakingModule: config::ConfigModule + events::GovEventsModule {
+    /// Stake governance tokens to gain voting power on every active proposal without sending tokens when voting.
+    /// Staked tokens count toward the first vote of the staker on each proposal, or toward their delegate's vote.
+    /// Payment:
+    ///     - token id must be equal to configured governance token id
+    ///     - tokens must be fungible
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "nothing to stake");
+        self.require_payments_with_primary_gov_token(&payments);
+
+        let staker = self.users().get_or_create_user(&caller);
+        let amount = self.stake_payments(staker, &payments);
+
+        self.stake_event(caller, amount);
+    }
+
+    /// Unstake governance tokens. They stop counting as voting power immediately
+    /// and can be claimed with `claimUnstaked` once the unbonding period has passed.
+    /// Unstaking again restarts the unbonding period for the full unbonding amount.
+    /// Fails while the stake counts toward a proposal that is still in its voting period,
+    /// or if it would leave less stake than is allocated to conviction proposals.
+    #[endpoint(unstake)]
+    fn unstake_endpoint(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let staker = self.users().get_user_id(&caller);
+        require!(amount > 0, "amount must be greater than zero");
+        require!(staker != 0 && self.stakes(staker).get() >= amount, "insufficient stake");
+
+        let unbonds_at = self.unstake(staker, &amount);
+
+        self.unstake_event(caller, amount, unbonds_at);
+    }
+
+    /// Claim unstaked governance tokens after the unbonding period has passed.
+    #[endpoint(claimUnstaked)]
+    fn claim_unstaked_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let staker = self.users().get_user_id(&caller);
+        require!(staker != 0 && !self.unbonding_stakes(staker).is_empty(), "nothing to claim");
+        require!(self.blockchain().get_block_timestamp() >= self.stake_unbonds_at(staker).get(), "stake is unbonding");
+
+        let amount = self.unbonding_stakes(staker).take();
+        self.stake_unbonds_at(staker).clear();
+
+        let gov_token = self.gov_token().get();
+        self.guarded_vote_tokens(&gov_token, 0).update(|current| *current -= &amount);
+        self.tx().to(&caller).single_esdt(&gov_token, 0, &amount).transfer();
+
+        self.claim_unstaked_event(caller, amount);
+    }
+
+    /// Change the period unstaked tokens have to wait before they can be claimed.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: unbonding period duration **in minutes**
+    #[endpoint(changeUnbondingPeriodMinutes)]
+    fn change_unbonding_period_in_minutes_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        require!(value <= UNBONDING_PERIOD_MINUTES_MAX, "max unbonding period exceeded");
+
+        self.unbonding_period_minutes().set(value);
+    }
+
+    /// Adds fungible governance token payments to the stake of a staker and returns the staked amount.
+    fn stake_payments(&self, staker: UserId, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) -> BigUint {
+        let amount = payments.iter().fold(BigUint::zero(), |carry, payment| {
+            require!(payment.token_nonce == 0, "staked tokens must be fungible");
+            carry + &payment.amount
+        });
+
+        let gov_token = self.gov_token().get();
+        self.guarded_vote_tokens(&gov_token, 0).update(|current| *current += &amount);
+        self.stakes(staker).update(|current| *current += &amount);
+        self.write_stake_checkpoint(staker);
+
+        let delegate = self.delegates(staker).get();
+        if delegate != 0 {
+            self.add_delegated_stake(delegate, &amount);
+        }
+
+        amount
+    }
+
+    /// Moves stake into unbonding and returns the time it can be claimed at.
+    fn unstake(&self, staker: UserId, amount: &BigUint) -> Timestamp {
+        require!(self.stakes(staker).get() - amount >= self.stake_allocations(staker).get(), "stake is allocated");
+
+        let now = self.blockchain().get_block_timestamp();
+        let delegate = self.delegates(staker).get();
+        require!(now >= self.stake_unlocks_at(staker).get(), "stake is locked");
+        require!(delegate == 0 || now >= self.delegated_stake_unlocks_at(delegate).get(), "stake is locked");
+
+        let unbonds_at = now + self.unbonding_period_minutes().get() as u64 * 60;
+
+        self.stakes(staker).update(|current| *current -= amount);
+        self.write_stake_checkpoint(staker);
+
+        if delegate != 0 {
+            self.remove_delegated_stake(delegate, amount);
+        }
+        self.unbonding_stakes(staker).update(|current| *current += amount);
+        self.stake_unbonds_at(staker).set(unbonds_at);
+
+        unbonds_at
+    }
+
+    /// Keeps the stake of a user locked until the voting period of the proposal has ended.
+    fn lock_stake_until(&self, staker: UserId, until: Timestamp) {
+        self.stake_unlocks_at(staker).update(|unlocks_at| {
+            if *unlocks_at < until {
+                *unlocks_at = until;
+            }
+        });
+    }
+
+    /// Keeps the stake delegated to a delegate locked until the voting period of the proposal has ended.
+    fn lock_delegated_stake_until(&self, delegate: UserId, until: Timestamp) {
+        self.delegated_stake_unlocks_at(delegate).update(|unlocks_at| {
+            if *unlocks_at < until {
+                *unlocks_at = until;
+            }
+        });
+    }
+
+    fn add_delegated_stake(&self, delegate: UserId, amount: &BigUint) {
+        if *amount == 0 {
+            return;
+        }
+
+        self.delegated_stakes(delegate).update(|current| *current += amount);
+        self.write_delegated_stake_checkpoint(delegate);
+    }
+
+    fn remove_delegated_stake(&self, delegate: UserId, amount: &BigUint) {
+        if *amount == 0 {
+            return;
+        }
+
+        self.delegated_stakes(delegate).update(|current| *current -= amount);
+        self.write_delegated_stake_checkpoint(delegate);
+    }
+
+    /// Records the total stake delegated to a delegate to evaluate it at the start of later proposals.
+    fn write_delegated_stake_checkpoint(&self, delegate: UserId) {
+        let checkpoint = StakeCheckpoint {
+            timestamp: self.blockchain().get_block_timestamp(),
+            amount: self.delegated_stakes(delegate).get(),
+        };
+
+        config::write_checkpoint(&mut self.delegated_stake_checkpoints(delegate), &checkpoint);
+    }
+
+    /// Returns the total stake delegated to a delegate right before the given timestamp.
+    fn get_delegated_stake_at(&self, delegate: UserId, timestamp: Timestamp) -> BigUint {
+        config::find_checkpoint_before(&self.delegated_stake_checkpoints(delegate), timestamp).map_or_else(BigUint::zero, |checkpoint| checkpoint.amount)
+    }
+
+    /// Records the current stake of a staker to evaluate it at the start of later proposals.
+    fn write_stake_checkpoint(&self, staker: UserId) {
+        let checkpoint = StakeCheckpoint {
+            timestamp: self.blockchain().get_block_timestamp(),
+            amount: self.stakes(staker).get(),
+        };
+
+        config::write_checkpoint(&mut self.stake_checkpoints(staker), &checkpoint);
+    }
+
+    /// Returns the stake of a staker right before the given timestamp.
+    /// Tokens staked at or after the timestamp carry no weight.
+    fn get_stake_at(&self, staker: UserId, timestamp: Timestamp) -> BigUint {
+        config::find_checkpoint_before(&self.stake_checkpoints(staker), timestamp).map_or_else(BigUint::zero, |checkpoint| checkpoint.amount)
+    }
+
+    #[view(getStake)]
+    fn get_stake_view(&self, address: ManagedAddress) -> BigUint {
+        let staker = self.users().get_user_id(&address);
+
+        self.stakes(staker).get()
+    }
+
+    #[view(getStakeAt)]
+    fn get_stake_at_view(&self, address: ManagedAddress, timestamp: Timestamp) -> BigUint {
+        let staker = self.users().get_user_id(&address);
+
+        self.get_stake_at(staker, timestamp)
+    }
+
+    #[view(getUnbondingStake)]
+    fn get_unbonding_stake_view(&self, address: ManagedAddress) -> MultiValue2<BigUint, Timestamp> {
+        let staker = self.users().get_user_id(&address);
+
+        (self.unbonding_stakes(staker).get(), self.stake_unbonds_at(staker).get()).into()
+    }
+
+    #[view(getUnbondingPeriodMinutes)]
+    #[storage_mapper("staking:unbonding_period_minutes")]
+    fn unbonding_period_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("staking:stakes")]
+    fn stakes(&self, staker: UserId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking:checkpoints")]
+    fn stake_checkpoints(&self, staker: UserId) -> VecMapper<StakeCheckpoint<Self::Api>>;
+
+    /// Total stake of the current delegators of a delegate.
+    #[storage_mapper("staking:delegated")]
+    fn delegated_stakes(&self, delegate: UserId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking:delegated_checkpoints")]
+    fn delegated_stake_checkpoints(&self, delegate: UserId) -> VecMapper<StakeCheckpoint<Self::Api>>;
+
+    #[storage_mapper("staking:delegated_unlocks_at")]
+    fn delegated_stake_unlocks_at(&self, delegate: UserId) -> SingleValueMapper<Timestamp>;
+
+    #[storage_mapper("staking:unlocks_at")]
+    fn stake_unlocks_at(&self, staker: UserId) -> SingleValueMapper<Timestamp>;
+
+    /// Stake committed as support to conviction proposals, which can not be unstaked.
+    #[storage_mapper("staking:allocated")]
+    fn stake_allocations(&self, staker: UserId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking:unbonding")]
+    fn unbonding_stakes(&self, staker: UserId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking:unbonds_at")]
+    fn stake_unbonds_at(&self, staker: UserId) -> SingleValueMapper<Timestamp>;
+}
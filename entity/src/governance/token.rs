@@ -1,30 +1,36 @@
 multiversx_sc::imports!();
 
-use crate::{config::{self}, permission::{self, ROLE_BUILTIN_LEADER}, plug};
+use crate::{
+    config::{self, GOV_TOKEN_MULTIPLIER_BASE, GOV_TOKEN_MULTIPLIER_MAX, TOKEN_MAX_DECIMALS},
+    permission::{self, QuorumMode, ROLE_BUILTIN_LEADER},
+    plug,
+};
 
-const DEFAULT_DECIMALS: usize = 18;
+use super::events;
 
 #[multiversx_sc::module]
-pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::PermissionModule {
+pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::PermissionModule + events::GovEventsModule {
     /// Initially configures the governance token if non is set already.
+    /// The supply is tracked to evaluate percentage-based quorums and kept up to date on `mint` and `burn`.
+    /// The optional decimals of the token are the common precision to combine token and plug weights, 18 if not set.
     /// It automatically calculates other governance setting defaults like quorum and minimum weight to propose.
     /// Can only be called by caller with leader role.
     #[endpoint(initGovToken)]
-    fn init_gov_token_endpoint(&self, token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool) {
+    fn init_gov_token_endpoint(&self, token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool, opt_decimals: OptionalValue<u8>) {
         require!(self.gov_token().is_empty(), "gov token is already set");
         require!(!self.is_plugged(), "already plugged");
         self.require_caller_has_leader_role();
 
-        self.configure_governance_token(token_id, supply, lock_vote_tokens);
+        self.configure_governance_token(token_id, supply, lock_vote_tokens, opt_decimals.into_option());
     }
 
-    /// Change the governance token.
+    /// Change the governance token along with its optional decimals. The current decimals are kept if not set.
     /// Automatically calculates other governance setting defaults like quorum and minimum weight to propose.
     /// Can only be called by the contract itself.
     #[endpoint(changeGovToken)]
-    fn change_gov_token_endpoint(&self, token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool) {
+    fn change_gov_token_endpoint(&self, token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool, opt_decimals: OptionalValue<u8>) {
         self.require_caller_self();
-        self.configure_governance_token(token_id, supply, lock_vote_tokens);
+        self.configure_governance_token(token_id, supply, lock_vote_tokens, opt_decimals.into_option());
     }
 
     /// Remove the governance token.
@@ -39,6 +45,71 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
         self.lock_vote_tokens(&removed_gov_token).clear();
     }
 
+    /// Change the decimals of the governance token, used as the common precision to combine token and plug weights.
+    /// Defaults to 18 decimals if no governance token was configured.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeGovTokenDecimals)]
+    fn change_gov_token_decimals_endpoint(&self, decimals: u8) {
+        self.require_caller_self();
+        require!(decimals <= TOKEN_MAX_DECIMALS, "invalid token decimals");
+
+        self.gov_token_decimals().set(Some(decimals));
+    }
+
+    #[view(getGovTokenDecimals)]
+    fn get_gov_token_decimals_view(&self) -> u8 {
+        self.get_gov_token_decimals()
+    }
+
+    /// Register an additional governance token that counts toward vote weight with a multiplier,
+    /// for example LP tokens counting 2x or a liquid staking token at its exchange rate.
+    /// Registering a token again updates its multiplier.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - token_id: the additional governance token
+    ///     - multiplier: vote weight per token **in basis points**, where 10_000 counts 1x
+    ///     - lock_vote_tokens: whether NFTs and SFTs of the token are locked when voting
+    ///     - supply: the total supply of the token, counted with the multiplier toward the circulating supply
+    #[endpoint(registerGovToken)]
+    fn register_gov_token_endpoint(&self, token_id: TokenIdentifier, multiplier: u64, lock_vote_tokens: bool, supply: BigUint) {
+        self.require_caller_self();
+        require!(token_id.is_valid_esdt_identifier(), "invalid token id");
+        require!(!self.is_gov_token(&token_id), "token is the governance token");
+        require!(multiplier > 0, "multiplier must be greater than zero");
+        require!(multiplier <= GOV_TOKEN_MULTIPLIER_MAX, "max multiplier exceeded");
+
+        self.gov_token_multipliers().insert(token_id.clone(), multiplier);
+        self.lock_vote_tokens(&token_id).set(lock_vote_tokens);
+        self.additional_gov_token_supply(&token_id).set(&supply);
+    }
+
+    /// Deregister an additional governance token. Tokens already used to vote remain withdrawable, but no longer count as vote weight.
+    /// Can only be called by the contract itself.
+    #[endpoint(deregisterGovToken)]
+    fn deregister_gov_token_endpoint(&self, token_id: TokenIdentifier) {
+        self.require_caller_self();
+        require!(self.gov_token_multipliers().remove(&token_id).is_some(), "token is not registered");
+
+        self.lock_vote_tokens(&token_id).clear();
+        self.additional_gov_token_supply(&token_id).clear();
+    }
+
+    /// Returns all governance tokens with their weight multiplier in basis points, starting with the governance token itself.
+    #[view(getGovTokens)]
+    fn get_gov_tokens_view(&self) -> MultiValueEncoded<MultiValue2<TokenIdentifier, u64>> {
+        let mut tokens = MultiValueEncoded::new();
+
+        if !self.gov_token().is_empty() {
+            tokens.push((self.gov_token().get(), GOV_TOKEN_MULTIPLIER_BASE).into());
+        }
+
+        for (token, multiplier) in self.gov_token_multipliers().iter() {
+            tokens.push((token, multiplier).into());
+        }
+
+        tokens
+    }
+
     /// Issue and configure a fresh governance ESDT owned by the smart contract.
     /// It automatically calculates other governance setting defaults like quorum and minimum weight to propose.
     /// The initially minted tokens (supply) will be send to the caller.
@@ -52,13 +123,13 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
 
         let caller = self.blockchain().get_caller();
         let user_id = self.users().get_user_id(&caller);
-        let is_leader = self.user_roles(user_id).contains(&ManagedBuffer::from(ROLE_BUILTIN_LEADER));
+        let is_leader = self.has_active_role_assignment(user_id, &ManagedBuffer::from(ROLE_BUILTIN_LEADER));
 
         require!(is_leader, "only allowed for leader");
         require!(supply > 0, "amount must be greater than zero");
 
         let properties = FungibleTokenProperties {
-            num_decimals: DEFAULT_DECIMALS,
+            num_decimals: TOKEN_MAX_DECIMALS as usize,
             can_burn: false,
             can_mint: false,
             can_freeze: true,
@@ -90,7 +161,7 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
         self.tx()
             .to(ESDTSystemSCAddress)
             .typed(ESDTSystemSCProxy)
-            .set_special_roles(&entity_address, &gov_token, (&roles[..]).into_iter().cloned())
+            .set_special_roles(&entity_address, &gov_token, roles[..].iter().cloned())
             .async_call_and_exit();
     }
 
@@ -100,6 +171,8 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
     fn mint_endpoint(&self, token: TokenIdentifier, nonce: u64, amount: BigUint) {
         self.require_caller_self();
 
+        self.update_gov_token_supply(&token, |supply| *supply += &amount);
+
         self.tx()
             .to(ToSelf)
             .typed(system_proxy::UserBuiltinProxy)
@@ -114,6 +187,8 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
         self.require_caller_self();
         self.require_tokens_available(&token, nonce, &amount); // TODO: TEST
 
+        self.update_gov_token_supply(&token, |supply| *supply = if *supply > amount { &*supply - &amount } else { BigUint::zero() });
+
         self.tx()
             .to(ToSelf)
             .typed(system_proxy::UserBuiltinProxy)
@@ -127,34 +202,57 @@ pub trait TokenModule: config::ConfigModule + plug::PlugModule + permission::Per
         match result {
             ManagedAsyncCallResult::Ok(_) => {
                 let payment = self.call_value().single_esdt();
-                self.configure_governance_token(payment.token_identifier, payment.amount, true);
+                self.configure_governance_token(payment.token_identifier, payment.amount, true, Some(TOKEN_MAX_DECIMALS));
             }
-            ManagedAsyncCallResult::Err(_) => self.send_received_egld(&initial_caller),
+            ManagedAsyncCallResult::Err(_) => self.send_received_egld(initial_caller),
         }
     }
 
-    fn configure_governance_token(&self, gov_token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool) {
+    fn configure_governance_token(&self, gov_token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool, decimals: Option<u8>) {
+        if let Some(decimals) = decimals {
+            require!(decimals <= TOKEN_MAX_DECIMALS, "invalid token decimals");
+            self.gov_token_decimals().set(Some(decimals));
+        }
+
         self.try_change_governance_token(&gov_token_id);
+        self.gov_token_multipliers().remove(&gov_token_id);
+        self.additional_gov_token_supply(&gov_token_id).clear();
         self.lock_vote_tokens(&gov_token_id).set(lock_vote_tokens);
+        self.gov_token_supply().set(&supply);
 
         if supply == 0 {
             return;
         }
 
-        let initial_quorum = if &supply > &BigUint::from(100u64) {
+        let initial_quorum = if supply > 100u64 {
             &supply * &BigUint::from(5u64) / &BigUint::from(100u64) // 5% of supply
         } else {
             BigUint::from(1u64)
         };
 
-        let initial_min_tokens_for_proposing = if &supply > &BigUint::from(100u64) {
+        let initial_min_tokens_for_proposing = if supply > 100u64 {
             &supply / &BigUint::from(100u64) // 1% of supply
         } else {
             BigUint::from(1u64)
         };
 
-        self.try_change_quorum(BigUint::from(initial_quorum));
-        self.try_change_min_propose_weight(BigUint::from(initial_min_tokens_for_proposing));
+        self.try_change_quorum_fallback(initial_quorum.clone());
+        self.try_change_default_quorum_mode(&initial_quorum, QuorumMode::Absolute);
+        self.try_change_default_quorum(initial_quorum);
+        self.try_change_min_propose_weight(initial_min_tokens_for_proposing);
+    }
+
+    fn is_gov_token(&self, token: &TokenIdentifier) -> bool {
+        !self.gov_token().is_empty() && &self.gov_token().get() == token
+    }
+
+    /// Updates the tracked supply of the governance token or of a registered additional governance token.
+    fn update_gov_token_supply<F: FnOnce(&mut BigUint)>(&self, token: &TokenIdentifier, f: F) {
+        if self.is_gov_token(token) {
+            self.gov_token_supply().update(f);
+        } else if self.gov_token_multipliers().contains_key(token) {
+            self.additional_gov_token_supply(token).update(f);
+        }
     }
 
     fn send_received_egld(&self, to: &ManagedAddress) {
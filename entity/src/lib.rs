@@ -3,6 +3,7 @@
 multiversx_sc::imports!();
 
 pub mod config;
+pub mod contract;
 pub mod governance;
 pub mod permission;
 pub mod plug;
@@ -13,9 +14,14 @@ use crate::permission::ROLE_BUILTIN_LEADER;
 #[multiversx_sc::contract]
 pub trait Entity:
     config::ConfigModule
+    + contract::ContractModule
     + permission::PermissionModule
     + plug::PlugModule
     + governance::GovernanceModule
+    + governance::conviction::ConvictionModule
+    + governance::delegation::DelegationModule
+    + governance::staking::StakingModule
+    + governance::escrow::EscrowModule
     + governance::events::GovEventsModule
     + governance::proposal::ProposalModule
     + governance::token::TokenModule
@@ -30,18 +36,19 @@ pub trait Entity:
     #[upgrade]
     fn upgrade(&self, trusted_host: ManagedAddress) {
         self.trusted_host_address().set(&trusted_host);
+        self.migrate_legacy_plug();
+        self.index_role_members();
     }
 
-    // TODO: add tests
     #[endpoint(setLeaderlessMode)]
     fn set_leaderless_mode_endpoint(&self) {
         self.require_caller_self();
         self.require_weighted_gov_method();
-
-        // TODO: make sure all leaders are unassigned (without iterating over all users)
+        require!(self.role_members_indexed().get(), "role members not indexed");
 
         let leader_role = ManagedBuffer::from(ROLE_BUILTIN_LEADER);
 
+        // removing the role unassigns every leader through the role member index
         self.remove_role(leader_role);
     }
 
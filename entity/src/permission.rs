@@ -1,10 +1,20 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::{config::{self, UserId, VOTING_PERIOD_MINUTES_MAX}, plug};
+use multiversx_sc::codec::{DecodeErrorHandler, TopDecodeInput};
+
+use crate::{
+    config::{
+        self, Timestamp, UserId, APPROVAL_THRESHOLD_DEFAULT, APPROVAL_THRESHOLD_MAX, EXECUTION_DELAY_MINUTES_MAX, EXECUTION_WINDOW_MINUTES_MAX, ROLE_HIERARCHY_DEPTH_MAX, VOTING_PERIOD_MINUTES_MAX,
+    },
+    governance::events,
+    plug,
+};
 
 pub const ROLE_BUILTIN_LEADER: &[u8] = b"leader";
 pub const ROLE_BUILTIN_MEMBER: &[u8] = b"member";
+pub const ROLE_BUILTIN_DEVELOPER: &[u8] = b"developer";
+pub const ROLE_BUILTIN_GUARDIAN: &[u8] = b"guardian";
 pub const PERMISSION_WILDCARD: &[u8] = b"*";
 pub const PERMISSION_NOOP: &[u8] = b"-";
 
@@ -45,11 +55,11 @@ impl<M: ManagedTypeApi> PermissionDetails<M> {
     }
 
     pub fn is_noop(&self) -> bool {
-        self.value.as_ref().map_or(true, |v| v == &0)
-        && self.destination.as_ref().map_or(false, |d| d.is_zero())
-        && self.endpoint.as_ref().map_or(false, |e| e.is_empty())
-        && self.arguments.as_ref().map_or(false, |a| a.is_empty())
-        && self.payments.as_ref().map_or(false, |p| p.is_empty())
+        self.value.as_ref().is_none_or(|v| v == &0)
+        && self.destination.as_ref().is_some_and(|d| d.is_zero())
+        && self.endpoint.as_ref().is_some_and(|e| e.is_empty())
+        && self.arguments.as_ref().is_some_and(|a| a.is_empty())
+        && self.payments.as_ref().is_some_and(|p| p.is_empty())
     }
 
     pub fn is_wildcard(&self) -> bool {
@@ -62,11 +72,41 @@ impl<M: ManagedTypeApi> PermissionDetails<M> {
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+#[derive(TopEncode, NestedEncode, NestedDecode, ManagedVecItem)]
 pub struct Policy<M: ManagedTypeApi> {
     pub method: PolicyMethod,
     pub quorum: BigUint<M>,
     pub voting_period_minutes: usize,
+    pub quorum_mode: QuorumMode,
+    pub execution_delay_minutes: usize,
+    pub execution_window_minutes: usize,
+    /// Share of FOR votes in total FOR and AGAINST votes required to pass, in basis points.
+    pub approval_threshold: u64,
+}
+
+// policies stored before the quorum mode, timelock and approval threshold existed end after the voting period
+impl<M: ManagedTypeApi> TopDecode for Policy<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        let mut input = input.into_nested_buffer();
+
+        let policy = Self {
+            method: PolicyMethod::dep_decode_or_handle_err(&mut input, h)?,
+            quorum: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            voting_period_minutes: usize::dep_decode_or_handle_err(&mut input, h)?,
+            quorum_mode: config::dep_decode_trailing_or_default(&mut input, h, || QuorumMode::Absolute)?,
+            execution_delay_minutes: config::dep_decode_trailing_or_default(&mut input, h, || 0)?,
+            execution_window_minutes: config::dep_decode_trailing_or_default(&mut input, h, || 0)?,
+            approval_threshold: config::dep_decode_trailing_or_default(&mut input, h, || APPROVAL_THRESHOLD_DEFAULT)?,
+        };
+
+        config::require_input_depleted(&input, h)?;
+
+        Ok(policy)
+    }
 }
 
 #[type_abi]
@@ -77,9 +117,33 @@ pub enum PolicyMethod {
     All,
     Quorum,
     Majority,
+    Quadratic,
+}
+
+/// Defines how the quorum of token-weighted policies is interpreted.
+/// - Absolute: the quorum is a fixed amount of vote weight
+/// - Percentage: the quorum is a share of the circulating voting power in basis points, evaluated on every status check
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug, ManagedVecItem)]
+pub enum QuorumMode {
+    Absolute,
+    Percentage,
+}
+
+impl QuorumMode {
+    pub fn to_name(&self) -> &[u8] {
+        match self {
+            QuorumMode::Absolute => b"absolute",
+            QuorumMode::Percentage => b"percentage",
+        }
+    }
 }
 
 impl PolicyMethod {
+    pub fn is_token_weighted(&self) -> bool {
+        matches!(self, PolicyMethod::Weight | PolicyMethod::Quadratic)
+    }
+
     pub fn to_name(&self) -> &[u8] {
         match self {
             PolicyMethod::Weight => b"weight",
@@ -87,16 +151,68 @@ impl PolicyMethod {
             PolicyMethod::All => b"all",
             PolicyMethod::Quorum => b"quorum",
             PolicyMethod::Majority => b"majority",
+            PolicyMethod::Quadratic => b"quadratic",
         }
     }
 }
 
+/// Defines how membership of a plug-bound role is looked up in the plug:
+/// - MemberList: the address must be listed with a non-zero weight in `getDaoMembers`
+/// - WeightView: the address must have a non-zero weight in `getDaoVoteWeight`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum RolePlugLookup {
+    MemberList,
+    WeightView,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct RolePlug<M: ManagedTypeApi> {
+    pub plug: ManagedAddress<M>,
+    pub lookup: RolePlugLookup,
+}
+
+/// Grants the members of a role the right to assign and unassign members of another role.
+/// At most `max_changes` assignments and unassignments are allowed per period. Zero disables the cap.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub struct RoleAdminPolicy {
+    pub max_changes: u32,
+    pub period_minutes: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Default)]
+pub struct RoleAdminUsage {
+    pub period_started_at: Timestamp,
+    pub changes: u32,
+}
+
 #[multiversx_sc::module]
-pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
+pub trait PermissionModule: config::ConfigModule + plug::PlugModule + events::GovEventsModule {
     fn init_permission_module(&self, leader: ManagedAddress) {
+        self.role_members_indexed().set(true);
         self.configure_leader_role(leader);
     }
 
+    /// Indexes the members of roles assigned before role members were tracked next to `user_roles`.
+    /// Runs once, on the first upgrade that tracks them.
+    fn index_role_members(&self) {
+        if self.role_members_indexed().get() {
+            return;
+        }
+
+        for user in 1..=self.users().get_user_count() {
+            for role in self.user_roles(user).iter() {
+                self.role_members(&role).insert(user);
+                self.members().insert(user);
+            }
+        }
+
+        self.role_members_indexed().set(true);
+    }
+
     /// Assigns the creator of the entity, creates a wildcard permission, and connects the two with a majority policy.
     fn configure_leader_role(&self, leader: ManagedAddress) {
         let role = ManagedBuffer::from(ROLE_BUILTIN_LEADER);
@@ -132,11 +248,14 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     /// Assign a custom role to the given user.
+    /// An optional expiry timestamp ends the assignment automatically. Assigning without expiry makes it permanent.
+    /// The leader role can not be assigned with an expiry.
     /// Can only be called by the contract itself.
     #[endpoint(assignRole)]
-    fn assign_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress) {
+    fn assign_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress, opt_expires_at: OptionalValue<Timestamp>) {
         self.require_caller_self();
-        self.assign_role(address, role);
+        self.assign_role(address.clone(), role.clone());
+        self.set_role_expiry(&address, &role, opt_expires_at.into_option());
     }
 
     /// Unassign a custom role from the given user.
@@ -147,6 +266,137 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         self.unassign_role(address, role);
     }
 
+    /// Allow members of the admin role to manage the membership of a role via `adminAssignRole` and `adminUnassignRole`.
+    /// Arguments:
+    ///     - max_changes: maximum assignments and unassignments per period, zero for no cap
+    ///     - period_minutes: length of the period the cap applies to
+    /// Built-in roles and roles inheriting from the leader role can not be delegated,
+    /// and a role can not administer itself or a role that administers it.
+    /// Can only be called by the contract itself.
+    #[endpoint(grantRoleAdmin)]
+    fn grant_role_admin_endpoint(&self, admin_role: RoleName<Self::Api>, role: RoleName<Self::Api>, max_changes: u32, period_minutes: usize) {
+        self.require_caller_self();
+        require!(self.roles().contains(&admin_role), "admin role does not exist");
+        require!(self.roles().contains(&role), "role does not exist");
+        require!(!self.inherits_leader_role(&role), "leader role can not be delegated");
+        require!(!self.is_builtin_role(&role), "built-in roles can not be delegated");
+        require!(admin_role != role, "role can not administer itself");
+        require!(!self.is_role_admin_of(&role, &admin_role), "role already administers the admin role");
+        require!(max_changes == 0 || period_minutes > 0, "period must be greater than zero");
+
+        self.role_admins(&role).insert(admin_role.clone(), RoleAdminPolicy { max_changes, period_minutes });
+        self.role_admin_usage(&admin_role, &role).clear();
+    }
+
+    /// Revoke the right of the admin role to manage the membership of a role.
+    /// Can only be called by the contract itself.
+    #[endpoint(revokeRoleAdmin)]
+    fn revoke_role_admin_endpoint(&self, admin_role: RoleName<Self::Api>, role: RoleName<Self::Api>) {
+        self.require_caller_self();
+        require!(self.role_admins(&role).remove(&admin_role).is_some(), "role admin does not exist");
+
+        self.role_admin_usage(&admin_role, &role).clear();
+    }
+
+    /// Assign a role to the given user as a member of an admin role of that role.
+    /// An optional expiry timestamp ends the assignment automatically.
+    #[endpoint(adminAssignRole)]
+    fn admin_assign_role_endpoint(&self, role: RoleName<Self::Api>, address: ManagedAddress, opt_expires_at: OptionalValue<Timestamp>) {
+        let caller = self.blockchain().get_caller();
+        self.use_role_admin_change(&caller, &role);
+
+        self.assign_role(address.clone(), role.clone());
+        self.set_role_expiry(&address, &role, opt_expires_at.into_option());
+        self.role_admin_assign_event(&caller, &role, &address);
+    }
+
+    /// Unassign a role from the given user as a member of an admin role of that role.
+    #[endpoint(adminUnassignRole)]
+    fn admin_unassign_role_endpoint(&self, role: RoleName<Self::Api>, address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.use_role_admin_change(&caller, &role);
+
+        self.unassign_role(address.clone(), role.clone());
+        self.role_admin_unassign_event(&caller, &role, &address);
+    }
+
+    #[view(getRoleAdmins)]
+    fn get_role_admins_view(&self, role: RoleName<Self::Api>) -> MultiValueEncoded<MultiValue3<RoleName<Self::Api>, u32, usize>> {
+        let mut admins = MultiValueEncoded::new();
+
+        for (admin_role, policy) in self.role_admins(&role).iter() {
+            admins.push((admin_role, policy.max_changes, policy.period_minutes).into());
+        }
+
+        admins
+    }
+
+    /// Bind a role to a plug smart contract, such as an NFT club, that defines additional members of the role.
+    /// Assigned members keep the role. With a member list lookup, the member amount of the role also counts the plug's members.
+    /// The plug must be deployed in the same shard as the entity, as membership is looked up synchronously.
+    /// Can only be called by the contract itself.
+    #[endpoint(bindRolePlug)]
+    fn bind_role_plug_endpoint(&self, role: RoleName<Self::Api>, plug: ManagedAddress, lookup: RolePlugLookup) {
+        self.require_caller_self();
+        require!(self.roles().contains(&role), "role does not exist");
+
+        let shard = self.blockchain().get_shard_of_address(&self.blockchain().get_sc_address());
+        require!(self.blockchain().get_shard_of_address(&plug) == shard, "plug must be in the same shard");
+
+        self.role_plug(&role).set(RolePlug { plug, lookup });
+    }
+
+    /// Remove the plug binding of a role.
+    /// Can only be called by the contract itself.
+    #[endpoint(unbindRolePlug)]
+    fn unbind_role_plug_endpoint(&self, role: RoleName<Self::Api>) {
+        self.require_caller_self();
+        require!(!self.role_plug(&role).is_empty(), "role is not bound to a plug");
+
+        self.role_plug(&role).clear();
+    }
+
+    /// Let a role inherit all policies of a parent role that it does not define itself.
+    /// Policies are inherited across at most 5 levels of parents.
+    /// Can only be called by the contract itself.
+    #[endpoint(addRoleParent)]
+    fn add_role_parent_endpoint(&self, role: RoleName<Self::Api>, parent: RoleName<Self::Api>) {
+        self.require_caller_self();
+        require!(self.roles().contains(&role), "role does not exist");
+        require!(self.roles().contains(&parent), "parent role does not exist");
+        require!(!self.get_role_lineage(&parent).contains(&role), "role hierarchy must not be cyclic");
+
+        self.role_parents(&role).insert(parent);
+    }
+
+    /// Stop a role from inheriting the policies of a parent role.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeRoleParent)]
+    fn remove_role_parent_endpoint(&self, role: RoleName<Self::Api>, parent: RoleName<Self::Api>) {
+        self.require_caller_self();
+        require!(self.role_parents(&role).swap_remove(&parent), "parent role is not assigned");
+    }
+
+    /// Unassign all expired assignments of a role.
+    /// Can be called by anyone.
+    #[endpoint(pruneExpiredRoles)]
+    fn prune_expired_roles_endpoint(&self, role: RoleName<Self::Api>) {
+        require!(self.roles().contains(&role), "role does not exist");
+
+        let expired_users = self
+            .role_expiring_members(&role)
+            .iter()
+            .filter(|user| self.is_role_expired(*user, &role))
+            .collect::<ManagedVec<UserId>>();
+
+        for user in expired_users.iter() {
+            let address = self.users().get_user_address_unchecked(user);
+
+            self.unassign_role(address.clone(), role.clone());
+            self.role_expire_event(&role, &address);
+        }
+    }
+
     /// Create a permission.
     /// This permission can later be connected to custom roles through a policy.
     /// Can only be called by the contract itself.
@@ -161,8 +411,8 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         payments: Option<ManagedVec<EsdtTokenPayment>>,
     ) {
         self.require_caller_self();
-        require!(permission != ManagedBuffer::from(PERMISSION_WILDCARD), "wildcard permission cannot be created");
-        require!(permission != ManagedBuffer::from(PERMISSION_NOOP), "noop permission cannot be created");
+        require!(permission != *PERMISSION_WILDCARD, "wildcard permission cannot be created");
+        require!(permission != *PERMISSION_NOOP, "noop permission cannot be created");
 
         self.create_permission(permission, value, destination, endpoint, args, payments);
     }
@@ -179,20 +429,37 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     /// Create a policy that requires role members to vote based on the provided parameters in order to invoke the permission.
+    /// A provided quorum is absolute, otherwise the default quorum and its mode are used.
+    /// The approval threshold is given in basis points and defaults to 50%.
     /// Can only be called by the contract itself.
     #[endpoint(createPolicyWeighted)]
-    fn create_policy_weighted_endpoint(&self, role: RoleName<Self::Api>, permission: PermissionName<Self::Api>,  opt_quorum: Option<BigUint>, opt_voting_period_minutes: Option<usize>) {
+    fn create_policy_weighted_endpoint(
+        &self,
+        role: RoleName<Self::Api>,
+        permission: PermissionName<Self::Api>,
+        opt_quorum: Option<BigUint>,
+        opt_voting_period_minutes: Option<usize>,
+        opt_approval_threshold: Option<u64>,
+    ) {
         self.require_caller_self();
-        self.require_weighted_gov_method(); // TODO: TEST
-
-        let quorum = opt_quorum.unwrap_or_else(|| self.default_quorum().get());
-        require!(quorum > 0, "quorum must be greater than zero"); // TODO: TEST
-
-        let voting_period_minutes = opt_voting_period_minutes.unwrap_or_else(|| self.default_voting_period_minutes().get());
-        require!(voting_period_minutes > 0, "voting period must be greater than zero"); // TODO: TEST
-        require!(voting_period_minutes <= VOTING_PERIOD_MINUTES_MAX, "max voting period exceeded"); // TODO: TEST
+        self.create_token_weighted_policy(role, permission, PolicyMethod::Weight, opt_quorum, opt_voting_period_minutes, opt_approval_threshold);
+    }
 
-        self.create_policy(role, permission, PolicyMethod::Weight, quorum, voting_period_minutes);
+    /// Create a policy like `createPolicyWeighted` where the effective weight of each voter
+    /// is the integer square root of all tokens they committed to the proposal.
+    /// The quorum applies to the committed tokens, like for `createPolicyWeighted`.
+    /// Can only be called by the contract itself.
+    #[endpoint(createPolicyQuadratic)]
+    fn create_policy_quadratic_endpoint(
+        &self,
+        role: RoleName<Self::Api>,
+        permission: PermissionName<Self::Api>,
+        opt_quorum: Option<BigUint>,
+        opt_voting_period_minutes: Option<usize>,
+        opt_approval_threshold: Option<u64>,
+    ) {
+        self.require_caller_self();
+        self.create_token_weighted_policy(role, permission, PolicyMethod::Quadratic, opt_quorum, opt_voting_period_minutes, opt_approval_threshold);
     }
 
     /// Create a policy that allows permissions to be invoked unilaterally.
@@ -249,6 +516,62 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         require!(policy.is_some(), "policy does not exist");
     }
 
+    /// Set the minimum delay between a proposal succeeding and its actions becoming executable for a policy.
+    /// The entity default execution delay applies if it is longer.
+    /// Can only be called by the contract itself.
+    #[endpoint(setPolicyExecutionDelay)]
+    fn set_policy_execution_delay_endpoint(&self, role: RoleName<Self::Api>, permission: PermissionName<Self::Api>, execution_delay_minutes: usize) {
+        self.require_caller_self();
+        require!(execution_delay_minutes <= EXECUTION_DELAY_MINUTES_MAX, "max execution delay exceeded");
+
+        self.update_policy(&role, permission, |policy| policy.execution_delay_minutes = execution_delay_minutes);
+    }
+
+    /// Set the time window in which proposals applying a policy can be executed before they expire.
+    /// The entity default execution window applies if it is shorter. Zero disables expiry for the policy.
+    /// Can only be called by the contract itself.
+    #[endpoint(setPolicyExecutionWindow)]
+    fn set_policy_execution_window_endpoint(&self, role: RoleName<Self::Api>, permission: PermissionName<Self::Api>, execution_window_minutes: usize) {
+        self.require_caller_self();
+        require!(execution_window_minutes <= EXECUTION_WINDOW_MINUTES_MAX, "max execution window exceeded");
+
+        self.update_policy(&role, permission, |policy| policy.execution_window_minutes = execution_window_minutes);
+    }
+
+    /// Change the quorum of a token-weighted policy.
+    /// Arguments:
+    ///     - quorum: absolute vote weight, or share of the circulating voting power **in basis points** when using the percentage mode
+    ///     - mode: either `Absolute` or `Percentage`
+    /// Can only be called by the contract itself.
+    #[endpoint(setPolicyQuorum)]
+    fn set_policy_quorum_endpoint(&self, role: RoleName<Self::Api>, permission: PermissionName<Self::Api>, quorum: BigUint, mode: QuorumMode) {
+        self.require_caller_self();
+        require!(quorum > 0, "quorum must be greater than zero");
+        self.require_valid_quorum_for_mode(&quorum, mode);
+
+        self.update_policy(&role, permission, |policy| {
+            policy.quorum = quorum;
+            policy.quorum_mode = mode;
+        });
+    }
+
+    /// Set the share of FOR votes required for token-weighted proposals applying a policy to pass.
+    /// Arguments:
+    ///     - approval_threshold: share of FOR votes in total votes **in basis points**, e.g. 6667 for 66.67%
+    /// Can only be called by the contract itself.
+    #[endpoint(setPolicyApprovalThreshold)]
+    fn set_policy_approval_threshold_endpoint(&self, role: RoleName<Self::Api>, permission: PermissionName<Self::Api>, approval_threshold: u64) {
+        self.require_caller_self();
+        self.require_valid_approval_threshold(approval_threshold);
+
+        self.update_policy(&role, permission, |policy| policy.approval_threshold = approval_threshold);
+    }
+
+    #[view(getRoleMemberAmount)]
+    fn get_role_member_amount_view(&self, role: RoleName<Self::Api>) -> usize {
+        self.get_role_member_amount(&role)
+    }
+
     #[view(getUserRoles)]
     fn get_user_roles_view(&self, address: ManagedAddress) -> MultiValueEncoded<RoleName<Self::Api>> {
         let user_id = self.users().get_user_id(&address);
@@ -258,13 +581,59 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
             return roles;
         }
 
-        for role in self.user_roles(user_id).iter() {
+        for role in self.get_active_user_roles(user_id).into_iter() {
             roles.push(role);
         }
 
         roles
     }
 
+    /// Returns the timestamp at which the role assignment of the given address expires, or zero if it does not expire.
+    #[view(getRoleExpiresAt)]
+    fn get_role_expires_at_view(&self, address: ManagedAddress, role: RoleName<Self::Api>) -> Timestamp {
+        let user = self.users().get_user_id(&address);
+
+        if user == 0 {
+            return 0;
+        }
+
+        self.role_expires_at(user, &role).get()
+    }
+
+    /// Returns a page of the addresses assigned to a role, starting at the zero-based index `from`.
+    /// Members provided by a plug bound to the role are not listed.
+    #[view(getRoleMembers)]
+    fn get_role_members_view(&self, role: RoleName<Self::Api>, from: usize, size: usize) -> MultiValueEncoded<ManagedAddress> {
+        let mut members = MultiValueEncoded::new();
+        let role_members = self.role_members(&role);
+        let to = core::cmp::min(from.saturating_add(size), role_members.len());
+
+        for index in from..to {
+            let user = role_members.get_by_index(index + 1);
+            members.push(self.users().get_user_address_unchecked(user));
+        }
+
+        members
+    }
+
+    /// Returns a page of the addresses that are assigned to at least one role together with their roles,
+    /// starting at the zero-based index `from`.
+    #[view(getMembers)]
+    fn get_members_view(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedVec<RoleName<Self::Api>>>> {
+        let mut members = MultiValueEncoded::new();
+        let to = core::cmp::min(from.saturating_add(size), self.members().len());
+
+        for index in from..to {
+            let user = self.members().get_by_index(index + 1);
+            let address = self.users().get_user_address_unchecked(user);
+            let roles = self.user_roles(user).iter().collect::<ManagedVec<RoleName<Self::Api>>>();
+
+            members.push((address, roles).into());
+        }
+
+        members
+    }
+
     #[view(getPermissions)]
     fn get_permissions_view(&self) -> MultiValueEncoded<PermissionDetails<Self::Api>> {
         let mut permissions = MultiValueEncoded::new();
@@ -277,7 +646,7 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     #[view(getPolicies)]
-    fn get_policies_view(&self, role_name: ManagedBuffer) -> MultiValueEncoded<MultiValue4<ManagedBuffer, ManagedBuffer, BigUint, usize>> {
+    fn get_policies_view(&self, role_name: ManagedBuffer) -> MultiValueEncoded<MultiValue8<ManagedBuffer, ManagedBuffer, BigUint, ManagedBuffer, usize, usize, usize, u64>> {
         let mut policies = MultiValueEncoded::new();
 
         for (permission_name, policy) in self.policies(&role_name).iter() {
@@ -286,7 +655,11 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
                     permission_name,
                     ManagedBuffer::from(policy.method.to_name()),
                     policy.quorum,
+                    ManagedBuffer::from(policy.quorum_mode.to_name()),
                     policy.voting_period_minutes,
+                    policy.execution_delay_minutes,
+                    policy.execution_window_minutes,
+                    policy.approval_threshold,
                 )
                     .into(),
             );
@@ -306,10 +679,34 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
         self.roles().swap_remove(&role);
         self.roles_member_amount(&role).set(0);
+        self.role_plug(&role).clear();
 
         // TODO: remove the unassign responsibility from this function
-        for user_id in 1..=self.users().get_user_count() {
+        for user_id in self.role_members(&role).iter() {
             self.user_roles(user_id).swap_remove(&role);
+            self.role_expires_at(user_id, &role).clear();
+
+            if self.user_roles(user_id).is_empty() {
+                self.members().swap_remove(&user_id);
+            }
+        }
+
+        self.role_members(&role).clear();
+        self.role_expiring_members(&role).clear();
+        self.role_parents(&role).clear();
+
+        for admin_role in self.role_admins(&role).keys() {
+            self.role_admin_usage(&admin_role, &role).clear();
+        }
+
+        self.role_admins(&role).clear();
+
+        for other in self.roles().iter() {
+            self.role_parents(&other).swap_remove(&role);
+
+            if self.role_admins(&other).remove(&role).is_some() {
+                self.role_admin_usage(&role, &other).clear();
+            }
         }
     }
 
@@ -322,6 +719,8 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
         if self.user_roles(user_id).insert(role.clone()) {
             self.roles_member_amount(&role).update(|current| *current += 1);
+            self.role_members(&role).insert(user_id);
+            self.members().insert(user_id);
         }
     }
 
@@ -330,6 +729,15 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
         let user = self.users().get_or_create_user(&address);
 
+        // the entity becomes leaderless once the last leader is gone, which requires token-weighted governance
+        if role == *ROLE_BUILTIN_LEADER && self.user_roles(user).contains(&role) && self.roles_member_amount(&role).get() == 1 {
+            let has_gov_token = !self.gov_token().is_empty() || !self.gov_token_multipliers().is_empty();
+            require!(has_gov_token || self.is_plugged(), "can not remove last leader: gov token or plug required");
+
+            self.remove_role(role);
+            return;
+        }
+
         // TODO: enable and fix dependency
         // for proposal in self.active_proposals().iter() {
         //     self.unsign_for_role(user, proposal, &role); // TODO: TEST
@@ -337,7 +745,86 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
         if self.user_roles(user).swap_remove(&role) {
             self.roles_member_amount(&role).update(|current| *current -= 1);
+            self.role_members(&role).swap_remove(&user);
+            self.role_expiring_members(&role).swap_remove(&user);
+            self.role_expires_at(user, &role).clear();
+
+            if self.user_roles(user).is_empty() {
+                self.members().swap_remove(&user);
+            }
+        }
+    }
+
+    fn set_role_expiry(&self, address: &ManagedAddress, role: &RoleName<Self::Api>, expires_at: Option<Timestamp>) {
+        let user = self.users().get_user_id(address);
+
+        match expires_at {
+            Some(expires_at) => {
+                require!(*role != *ROLE_BUILTIN_LEADER, "leader role can not expire");
+                require!(expires_at > self.blockchain().get_block_timestamp(), "expiry must be in the future");
+
+                self.role_expires_at(user, role).set(expires_at);
+                self.role_expiring_members(role).insert(user);
+            }
+            None => {
+                self.role_expires_at(user, role).clear();
+                self.role_expiring_members(role).swap_remove(&user);
+            }
+        }
+    }
+
+    fn is_role_expired(&self, user: UserId, role: &RoleName<Self::Api>) -> bool {
+        let expires_at = self.role_expires_at(user, role).get();
+
+        expires_at != 0 && expires_at <= self.blockchain().get_block_timestamp()
+    }
+
+    fn has_active_role_assignment(&self, user: UserId, role: &RoleName<Self::Api>) -> bool {
+        self.user_roles(user).contains(role) && !self.is_role_expired(user, role)
+    }
+
+    fn get_active_user_roles(&self, user: UserId) -> ManagedVec<RoleName<Self::Api>> {
+        self.user_roles(user).iter().filter(|role| !self.is_role_expired(user, role)).collect()
+    }
+
+    /// Charges a membership change of a role to the first admin role of the caller that has not reached its cap.
+    fn use_role_admin_change(&self, caller: &ManagedAddress, role: &RoleName<Self::Api>) {
+        // a parent role may have been added after the admin role was granted
+        require!(!self.inherits_leader_role(role), "leader role can not be delegated");
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let mut is_admin = false;
+
+        for (admin_role, policy) in self.role_admins(role).iter() {
+            if !self.has_role(caller, &admin_role) {
+                continue;
+            }
+
+            is_admin = true;
+
+            if policy.max_changes == 0 {
+                return;
+            }
+
+            let usage_mapper = self.role_admin_usage(&admin_role, role);
+            let mut usage = if usage_mapper.is_empty() { RoleAdminUsage::default() } else { usage_mapper.get() };
+
+            if current_time >= usage.period_started_at + policy.period_minutes as u64 * 60 {
+                usage = RoleAdminUsage {
+                    period_started_at: current_time,
+                    changes: 0,
+                };
+            }
+
+            if usage.changes < policy.max_changes {
+                usage.changes += 1;
+                usage_mapper.set(usage);
+                return;
+            }
         }
+
+        require!(is_admin, "caller is not a role admin");
+        sc_panic!("role admin change limit reached");
     }
 
     fn create_permission(
@@ -371,36 +858,202 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
             Policy {
                 method,
                 quorum,
+                quorum_mode: QuorumMode::Absolute,
                 voting_period_minutes,
+                execution_delay_minutes: 0,
+                execution_window_minutes: 0,
+                approval_threshold: APPROVAL_THRESHOLD_DEFAULT,
             },
         );
     }
 
+    fn create_token_weighted_policy(
+        &self,
+        role: RoleName<Self::Api>,
+        permission: PermissionName<Self::Api>,
+        method: PolicyMethod,
+        opt_quorum: Option<BigUint>,
+        opt_voting_period_minutes: Option<usize>,
+        opt_approval_threshold: Option<u64>,
+    ) {
+        self.require_weighted_gov_method(); // TODO: TEST
+
+        let quorum_mode = if opt_quorum.is_some() { QuorumMode::Absolute } else { self.default_quorum_mode().get() };
+        let quorum = opt_quorum.unwrap_or_else(|| self.default_quorum().get());
+        require!(quorum > 0, "quorum must be greater than zero"); // TODO: TEST
+
+        let voting_period_minutes = opt_voting_period_minutes.unwrap_or_else(|| self.default_voting_period_minutes().get());
+        require!(voting_period_minutes > 0, "voting period must be greater than zero"); // TODO: TEST
+        require!(voting_period_minutes <= VOTING_PERIOD_MINUTES_MAX, "max voting period exceeded"); // TODO: TEST
+
+        let approval_threshold = opt_approval_threshold.unwrap_or(APPROVAL_THRESHOLD_DEFAULT);
+        self.require_valid_approval_threshold(approval_threshold);
+
+        self.create_policy(role.clone(), permission.clone(), method, quorum, voting_period_minutes);
+        self.update_policy(&role, permission, |policy| {
+            policy.quorum_mode = quorum_mode;
+            policy.approval_threshold = approval_threshold;
+        });
+    }
+
+    fn update_policy<F: FnOnce(&mut Policy<Self::Api>)>(&self, role: &RoleName<Self::Api>, permission: PermissionName<Self::Api>, f: F) {
+        let mut policy = self.policies(role).get(&permission).unwrap_or_else(|| sc_panic!("policy does not exist"));
+        f(&mut policy);
+
+        self.policies(role).insert(permission, policy);
+    }
+
+    /// Returns the policy of a role for a permission.
+    /// If the role does not define one, it is inherited from the closest ancestor role that does.
+    fn get_role_policy(&self, role: &RoleName<Self::Api>, permission: &PermissionName<Self::Api>) -> Option<Policy<Self::Api>> {
+        self.get_role_lineage(role).iter().find_map(|ancestor| self.policies(&ancestor).get(permission))
+    }
+
+    /// Returns the permissions for which a role defines or inherits a policy.
+    fn get_role_policy_permissions(&self, role: &RoleName<Self::Api>) -> ManagedVec<PermissionName<Self::Api>> {
+        let mut permissions = ManagedVec::<Self::Api, PermissionName<Self::Api>>::new();
+
+        for ancestor in self.get_role_lineage(role).iter() {
+            for permission in self.policies(&ancestor).keys() {
+                if !permissions.contains(&permission) {
+                    permissions.push(permission);
+                }
+            }
+        }
+
+        permissions
+    }
+
+    /// Checks whether the role is one of the roles the entity comes with.
+    fn is_builtin_role(&self, role: &RoleName<Self::Api>) -> bool {
+        *role == *ROLE_BUILTIN_LEADER || *role == *ROLE_BUILTIN_MEMBER || *role == *ROLE_BUILTIN_DEVELOPER || *role == *ROLE_BUILTIN_GUARDIAN
+    }
+
+    fn inherits_leader_role(&self, role: &RoleName<Self::Api>) -> bool {
+        self.get_role_lineage(role).contains(&ManagedBuffer::from(ROLE_BUILTIN_LEADER))
+    }
+
+    /// Checks whether the role or one of its ancestors administers the other role or one of its ancestors.
+    fn is_role_admin_of(&self, admin_role: &RoleName<Self::Api>, role: &RoleName<Self::Api>) -> bool {
+        let admin_lineage = self.get_role_lineage(admin_role);
+
+        self.get_role_lineage(role)
+            .iter()
+            .any(|administered| admin_lineage.iter().any(|admin| self.role_admins(&administered).contains_key(&admin)))
+    }
+
+    /// Returns the role followed by its ancestors, ordered from the closest to the most distant.
+    fn get_role_lineage(&self, role: &RoleName<Self::Api>) -> ManagedVec<RoleName<Self::Api>> {
+        let mut lineage = ManagedVec::from_single_item(role.clone());
+        let mut generation = lineage.clone();
+
+        for _ in 0..ROLE_HIERARCHY_DEPTH_MAX {
+            let mut parents = ManagedVec::<Self::Api, RoleName<Self::Api>>::new();
+
+            for member in generation.iter() {
+                for parent in self.role_parents(&member).iter() {
+                    if !lineage.contains(&parent) {
+                        lineage.push(parent.clone());
+                        parents.push(parent);
+                    }
+                }
+            }
+
+            if parents.is_empty() {
+                break;
+            }
+
+            generation = parents;
+        }
+
+        lineage
+    }
+
+    fn require_valid_approval_threshold(&self, approval_threshold: u64) {
+        require!(approval_threshold > 0, "approval threshold must be greater than zero");
+        require!(approval_threshold <= APPROVAL_THRESHOLD_MAX, "max approval threshold exceeded");
+    }
+
     fn has_role(&self, address: &ManagedAddress, role: &RoleName<Self::Api>) -> bool {
-        let user_id = self.users().get_user_id(&address);
+        let user_id = self.users().get_user_id(address);
 
-        if user_id == 0 {
+        if user_id != 0 && self.has_active_role_assignment(user_id, role) {
+            return true;
+        }
+
+        self.is_plug_role_member(address, role)
+    }
+
+    fn has_user_role(&self, user: UserId, role: &RoleName<Self::Api>) -> bool {
+        if self.has_active_role_assignment(user, role) {
+            return true;
+        }
+
+        if self.role_plug(role).is_empty() {
             return false;
         }
 
-        self.user_roles(user_id).contains(&role)
+        let address = self.users().get_user_address_unchecked(user);
+
+        self.is_plug_role_member(&address, role)
+    }
+
+    fn is_plug_role_member(&self, address: &ManagedAddress, role: &RoleName<Self::Api>) -> bool {
+        if self.role_plug(role).is_empty() {
+            return false;
+        }
+
+        let role_plug = self.role_plug(role).get();
+
+        match role_plug.lookup {
+            RolePlugLookup::MemberList => self.get_plug_members(role_plug.plug).iter().any(|member| &*member == address),
+            RolePlugLookup::WeightView => self.get_plug_user_weight(role_plug.plug, address) > 0,
+        }
+    }
+
+    /// Returns the amount of members of a role: the assigned members whose assignment has not expired,
+    /// plus the members of a plug bound with a member list lookup that are not assigned.
+    /// Members of a plug bound with a weight view lookup can not be listed and are not counted.
+    fn get_role_member_amount(&self, role: &RoleName<Self::Api>) -> usize {
+        let expired_amount = self.role_expiring_members(role).iter().filter(|user| self.is_role_expired(*user, role)).count();
+        let assigned_amount = self.roles_member_amount(role).get() - expired_amount;
+
+        if self.role_plug(role).is_empty() {
+            return assigned_amount;
+        }
+
+        let role_plug = self.role_plug(role).get();
+
+        if role_plug.lookup == RolePlugLookup::WeightView {
+            return assigned_amount;
+        }
+
+        let plug_only_amount = self
+            .get_plug_members(role_plug.plug)
+            .iter()
+            .filter(|member| {
+                let user = self.users().get_user_id(member);
+                user == 0 || !self.has_active_role_assignment(user, role)
+            })
+            .count();
+
+        assigned_amount + plug_only_amount
     }
 
     fn has_token_weighted_policy(&self, policies: &ManagedVec<Policy<Self::Api>>) -> bool {
-        policies.iter().find(|p| p.method == PolicyMethod::Weight).is_some()
+        policies.iter().find(|p| p.method.is_token_weighted()).is_some()
     }
 
     fn is_leaderless(&self) -> bool {
         let leader_role = ManagedBuffer::from(ROLE_BUILTIN_LEADER);
-        let is_leaderless = self.roles_member_amount(&leader_role).get() == 0;
 
-        is_leaderless
+        self.get_role_member_amount(&leader_role) == 0
     }
 
     fn has_leader_role(&self, address: &ManagedAddress) -> bool {
         let leader_role = ManagedBuffer::from(ROLE_BUILTIN_LEADER);
 
-        self.has_role(&address, &leader_role)
+        self.has_role(address, &leader_role)
     }
 
     fn require_caller_has_leader_role(&self) {
@@ -408,14 +1061,53 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         require!(self.has_leader_role(&caller), "caller must be leader");
     }
 
+    fn has_guardian_role(&self, address: &ManagedAddress) -> bool {
+        let guardian_role = ManagedBuffer::from(ROLE_BUILTIN_GUARDIAN);
+
+        self.has_role(address, &guardian_role)
+    }
+
+    fn require_caller_has_guardian_role(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_guardian_role(&caller), "caller must be guardian");
+    }
+
     #[view(getRoles)]
     #[storage_mapper("roles")]
     fn roles(&self) -> UnorderedSetMapper<RoleName<Self::Api>>;
 
-    #[view(getRoleMemberAmount)]
     #[storage_mapper("roles_member_amount")]
     fn roles_member_amount(&self, role: &RoleName<Self::Api>) -> SingleValueMapper<usize>;
 
+    #[view(getRolePlug)]
+    #[storage_mapper("role_plug")]
+    fn role_plug(&self, role: &RoleName<Self::Api>) -> SingleValueMapper<RolePlug<Self::Api>>;
+
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: &RoleName<Self::Api>) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("members")]
+    fn members(&self) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("role_members_indexed")]
+    fn role_members_indexed(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("role_admins")]
+    fn role_admins(&self, role: &RoleName<Self::Api>) -> MapMapper<RoleName<Self::Api>, RoleAdminPolicy>;
+
+    #[storage_mapper("role_admin_usage")]
+    fn role_admin_usage(&self, admin_role: &RoleName<Self::Api>, role: &RoleName<Self::Api>) -> SingleValueMapper<RoleAdminUsage>;
+
+    #[view(getRoleParents)]
+    #[storage_mapper("role_parents")]
+    fn role_parents(&self, role: &RoleName<Self::Api>) -> UnorderedSetMapper<RoleName<Self::Api>>;
+
+    #[storage_mapper("role_expires_at")]
+    fn role_expires_at(&self, user: UserId, role: &RoleName<Self::Api>) -> SingleValueMapper<Timestamp>;
+
+    #[storage_mapper("role_expiring_members")]
+    fn role_expiring_members(&self, role: &RoleName<Self::Api>) -> UnorderedSetMapper<UserId>;
+
     #[storage_mapper("user_roles")]
     fn user_roles(&self, user: UserId) -> UnorderedSetMapper<ManagedBuffer<Self::Api>>;
 
@@ -427,4 +1119,5 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
     #[storage_mapper("policies")]
     fn policies(&self, role: &RoleName<Self::Api>) -> MapMapper<ManagedBuffer<Self::Api>, Policy<Self::Api>>;
+
 }
@@ -1,6 +1,47 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
-use crate::config::{self, ProposalId, UserId};
+use crate::config::{self, ProposalId, ProposalOptionId, UserId, PLUG_MULTIPLIER_BASE};
+use crate::governance::proposal::VoteType;
+use core::cmp::Ordering;
+use plug_proxy::ProxyTrait as _;
+
+/// Defines how a failed weight query to a plug is handled:
+/// - Ignore: the plug is skipped and can be queried again on a later vote
+/// - Zero: the plug counts with zero weight and is considered used for the proposal
+/// - Abort: the whole vote is discarded, or the proposal canceled, while payments of a discarded vote stay locked until the proposal ended
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum PlugFailurePolicy {
+    Ignore,
+    Zero,
+    Abort,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub struct PlugSettings {
+    /// Vote weight per plug weight unit in basis points, where 10_000 counts 1x.
+    pub multiplier: u64,
+    pub weight_decimals: u8,
+    pub failure_policy: PlugFailurePolicy,
+}
+
+/// Collects the weight of a user from all plugs until every queried plug has responded.
+/// A missing vote type marks the weight of a proposer.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PlugWeightRequest<M: ManagedTypeApi> {
+    pub proposal: ProposalId,
+    pub user: UserId,
+    pub vote_type: Option<VoteType>,
+    pub option_id: ProposalOptionId,
+    pub payment_weight: BigUint<M>,
+    pub plug_weight: BigUint<M>,
+    pub counted_plugs: ManagedVec<M, ManagedAddress<M>>,
+    pub pending: usize,
+    pub aborted: bool,
+}
 
 #[multiversx_sc::module]
 pub trait PlugModule: config::ConfigModule {
@@ -8,15 +49,45 @@ pub trait PlugModule: config::ConfigModule {
     fn has_user_plug_voted_view(&self, proposal: ProposalId, address: ManagedAddress) -> bool {
         let user = self.users().get_user_id(&address);
 
-        self.has_user_plug_voted(proposal, user)
+        self.legacy_plug_votes(proposal).contains(&user) || self.proposal_plugs(proposal).iter().any(|plug| self.has_user_plug_voted(proposal, user, &plug))
     }
 
-    fn record_plug_vote(&self, voter: UserId, proposal: ProposalId) {
-        self.plug_votes(proposal).insert(voter);
+    fn record_plug_vote(&self, voter: UserId, proposal: ProposalId, plug: &ManagedAddress) {
+        self.plug_votes(proposal, plug).insert(voter);
+        self.proposal_plugs(proposal).insert(plug.clone());
     }
 
-    fn has_user_plug_voted(&self, proposal: ProposalId, user: UserId) -> bool {
-        self.plug_votes(proposal).contains(&user)
+    /// Registers the main plug set before multiple plugs were supported, so that its weight keeps counting.
+    fn migrate_legacy_plug(&self) {
+        if self.plug_contract().is_empty() {
+            return;
+        }
+
+        let plug = self.plug_contract().get();
+
+        if self.plugs().contains_key(&plug) {
+            return;
+        }
+
+        self.plugs().insert(
+            plug,
+            PlugSettings {
+                multiplier: PLUG_MULTIPLIER_BASE,
+                weight_decimals: self.plug_weight_decimals().get(),
+                failure_policy: PlugFailurePolicy::Abort,
+            },
+        );
+    }
+
+    /// Votes recorded before multiple plugs were supported count as votes with the main plug.
+    fn has_user_plug_voted(&self, proposal: ProposalId, user: UserId, plug: &ManagedAddress) -> bool {
+        if self.plug_votes(proposal, plug).contains(&user) {
+            return true;
+        }
+
+        let is_main_plug = !self.plug_contract().is_empty() && &self.plug_contract().get() == plug;
+
+        is_main_plug && self.legacy_plug_votes(proposal).contains(&user)
     }
 
     #[view(getPlug)]
@@ -27,6 +98,96 @@ pub trait PlugModule: config::ConfigModule {
         (plug_contract, weight_decimals).into()
     }
 
+    #[view(getPlugs)]
+    fn get_plugs_view(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, PlugSettings>> {
+        let mut plugs = MultiValueEncoded::new();
+
+        for (plug, settings) in self.plugs().iter() {
+            plugs.push((plug, settings).into());
+        }
+
+        plugs
+    }
+
+    /// Returns the total vote weight of all members of all plugs,
+    /// or none if a plug does not report its total weight.
+    fn get_plug_total_weight(&self) -> Option<BigUint> {
+        let token = self.get_gov_token_option();
+        let mut total_weight = BigUint::zero();
+
+        for (plug, settings) in self.plugs().iter() {
+            if !self.plug_reports_total_weight(&plug).get() {
+                return None;
+            }
+
+            let plug_weight = self
+                .tx()
+                .legacy_proxy_call(self.plug_proxy(plug).get_dao_vote_weight_total_view(OptionalValue::from(token.clone())))
+                .returns(ReturnsResult)
+                .sync_call_readonly();
+
+            total_weight += self.normalize_plug_weight(&settings, plug_weight);
+        }
+
+        Some(total_weight)
+    }
+
+    /// Returns the addresses with a non-zero vote weight in the given plug.
+    /// Requires the plug to be deployed in the same shard as the entity.
+    fn get_plug_members(&self, plug: ManagedAddress) -> ManagedVec<ManagedAddress> {
+        let token = self.get_gov_token_option();
+
+        self.tx()
+            .legacy_proxy_call(self.plug_proxy(plug).get_dao_members_view(OptionalValue::from(token)))
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_iter()
+            .filter_map(|member| {
+                let (address, weight) = member.into_tuple();
+                if weight > 0 {
+                    Some(address)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the unnormalized vote weight of the given address in the given plug.
+    /// Requires the plug to be deployed in the same shard as the entity.
+    fn get_plug_user_weight(&self, plug: ManagedAddress, address: &ManagedAddress) -> BigUint {
+        let token = self.get_gov_token_option();
+
+        self.tx()
+            .legacy_proxy_call(self.plug_proxy(plug).get_dao_vote_weight_view(address, OptionalValue::from(token)))
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    /// Scales a vote weight reported by a plug from its weight decimals to the common precision and applies its multiplier,
+    /// so that it can be combined with token payment weight and compared with quorum and min weights.
+    fn normalize_plug_weight(&self, settings: &PlugSettings, weight: BigUint) -> BigUint {
+        let common_decimals = self.get_common_weight_decimals();
+
+        let scaled_weight = match common_decimals.cmp(&settings.weight_decimals) {
+            Ordering::Greater => weight * BigUint::from(10u64).pow((common_decimals - settings.weight_decimals) as u32),
+            Ordering::Less => weight / BigUint::from(10u64).pow((settings.weight_decimals - common_decimals) as u32),
+            Ordering::Equal => weight,
+        };
+
+        scaled_weight * settings.multiplier / PLUG_MULTIPLIER_BASE
+    }
+
+    /// Returns the decimals all vote weights are normalized to:
+    /// the decimals of the governance token, or of the main plug if there is no governance token.
+    fn get_common_weight_decimals(&self) -> u8 {
+        if self.gov_token().is_empty() {
+            return self.plug_weight_decimals().get();
+        }
+
+        self.get_gov_token_decimals()
+    }
+
     #[proxy]
     fn plug_proxy(&self, to: ManagedAddress) -> plug_proxy::Proxy<Self::Api>;
 }
@@ -38,5 +199,11 @@ pub mod plug_proxy {
     pub trait EntityPlugContractProxy {
         #[view(getDaoVoteWeight)]
         fn get_dao_vote_weight_view(&self, address: ManagedAddress, token: OptionalValue<TokenIdentifier>) -> BigUint;
+
+        #[view(getDaoMembers)]
+        fn get_dao_members_view(&self, token: OptionalValue<TokenIdentifier>) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>;
+
+        #[view(getDaoVoteWeightTotal)]
+        fn get_dao_vote_weight_total_view(&self, token: OptionalValue<TokenIdentifier>) -> BigUint;
     }
 }
@@ -1,7 +1,7 @@
 use entity::config::*;
+use entity::permission::ROLE_BUILTIN_MEMBER;
 use entity::governance::proposal::*;
 use entity::governance::*;
-use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -14,6 +14,7 @@ fn it_cancels_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -30,7 +31,8 @@ fn it_cancels_a_proposal() {
                     managed_buffer!(b"content_sig"),
                     managed_buffer!(b""),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -59,6 +61,7 @@ fn it_fails_when_caller_not_proposer() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -75,7 +78,8 @@ fn it_fails_when_caller_not_proposer() {
                     managed_buffer!(b"content_sig"),
                     managed_buffer!(b""),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -98,6 +102,7 @@ fn it_fails_when_proposal_not_active() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -114,7 +119,8 @@ fn it_fails_when_proposal_not_active() {
                     managed_buffer!(b"content_sig"),
                     managed_buffer!(b""),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -127,5 +133,5 @@ fn it_fails_when_proposal_not_active() {
         .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.cancel_proposal_endpoint(proposal_id);
         })
-        .assert_user_error(PROPOSAL_NOT_ACTIVE);
+        .assert_user_error("proposal not active");
 }
@@ -1,6 +1,7 @@
+use multiversx_sc::codec::multi_types::*;
 use multiversx_sc_scenario::*;
 use entity::config::*;
-use entity::governance::*;
+use entity::governance::token::*;
 use setup::*;
 
 mod setup;
@@ -12,7 +13,7 @@ fn it_changes_the_governance_token() {
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true);
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::None);
 
             assert_eq!(sc.gov_token().get(), managed_token_id!(b"GOV-123456"));
         })
@@ -26,7 +27,7 @@ fn it_changes_the_governance_token_even_when_supply_lower_than_one_hundred() {
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(5), true);
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(5), true, OptionalValue::None);
 
             assert_eq!(sc.gov_token().get(), managed_token_id!(b"GOV-123456"));
         })
@@ -40,7 +41,53 @@ fn it_fails_when_caller_not_self() {
     setup
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true);
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::None);
         })
         .assert_user_error("action not allowed by user");
 }
+
+#[test]
+fn it_replaces_the_decimals_of_the_previous_governance_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+    setup.configure_gov_token_decimals(18);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::Some(6));
+
+            assert_eq!(6, sc.get_gov_token_decimals_view());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_decimals_when_changing_the_governance_token_without_decimals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+    setup.configure_gov_token_decimals(6);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::None);
+
+            assert_eq!(6, sc.get_gov_token_decimals_view());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_decimals_are_invalid() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::Some(TOKEN_MAX_DECIMALS + 1));
+        })
+        .assert_user_error("invalid token decimals");
+}
@@ -1,5 +1,6 @@
+use multiversx_sc::codec::multi_types::*;
 use entity::config::*;
-use entity::governance::*;
+use entity::governance::token::*;
 use entity::permission::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -21,7 +22,7 @@ fn it_sets_the_governance_token_initially_by_leader() {
     setup
         .blockchain
         .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.init_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true);
+            sc.init_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::None);
 
             assert_eq!(sc.gov_token().get(), managed_token_id!(b"GOV-123456"));
         })
@@ -35,7 +36,7 @@ fn it_fails_when_caller_not_leader() {
     setup
         .blockchain
         .execute_tx(&setup.user_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.init_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true);
+            sc.init_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true, OptionalValue::None);
         })
         .assert_user_error("caller must be leader");
 }
@@ -49,7 +50,7 @@ fn it_fails_when_gov_token_already_set() {
         .execute_tx(&setup.user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.gov_token().set(managed_token_id!(b"GOV-123456"));
 
-            sc.init_gov_token_endpoint(managed_token_id!(b"NEW-123456"), managed_biguint!(1_000), true);
+            sc.init_gov_token_endpoint(managed_token_id!(b"NEW-123456"), managed_biguint!(1_000), true, OptionalValue::None);
         })
         .assert_user_error("gov token is already set");
 }
@@ -1,5 +1,5 @@
 use entity::config::*;
-use entity::governance::*;
+use entity::governance::token::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -14,7 +14,7 @@ fn it_issues_a_governance_token() {
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0.5), |sc| {
             sc.issue_gov_token_endpoint(managed_buffer!(b"Token"), managed_buffer!(b"Token-123456"), managed_biguint!(100_000));
 
-            assert!(!sc.quorum().is_empty());
+            assert!(!sc.default_quorum().is_empty());
             assert!(!sc.min_propose_weight().is_empty());
             assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), sc.gov_token().get());
         })
@@ -0,0 +1,212 @@
+use entity::config::*;
+use entity::governance::token::*;
+use entity::governance::proposal::*;
+use entity::governance::staking::*;
+use entity::governance::*;
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_registers_an_additional_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+
+            assert_eq!(Some(20_000), sc.gov_token_multipliers().get(&managed_token_id!(ENTITY_FAKE_TOKEN_ID)));
+            assert!(sc.lock_vote_tokens(&managed_token_id!(ENTITY_FAKE_TOKEN_ID)).get());
+            assert_eq!(managed_biguint!(0), sc.additional_gov_token_supply(&managed_token_id!(ENTITY_FAKE_TOKEN_ID)).get());
+
+            let tokens = sc.get_gov_tokens_view().into_iter().collect::<Vec<_>>();
+            assert_eq!(2, tokens.len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_register_the_gov_token_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 20_000, true, managed_biguint!(0));
+        })
+        .assert_user_error("token is the governance token");
+}
+
+#[test]
+fn it_fails_to_register_with_a_multiplier_above_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), GOV_TOKEN_MULTIPLIER_MAX + 1, true, managed_biguint!(0));
+        })
+        .assert_user_error("max multiplier exceeded");
+}
+
+#[test]
+fn it_fails_to_register_if_not_called_by_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_weights_payments_of_all_gov_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+
+            let mut payments = ManagedVec::new();
+            payments.push(EsdtTokenPayment::new(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, managed_biguint!(10)));
+            payments.push(EsdtTokenPayment::new(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 0, managed_biguint!(5)));
+
+            sc.require_payments_with_gov_token(&payments);
+
+            assert_eq!(managed_biguint!(20), sc.get_vote_weight_from_payments(&payments, 1, None));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_guards_vote_tokens_per_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 15_000, true, managed_biguint!(0));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_FAKE_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            let payments = sc.call_value().all_esdt_transfers().clone_value();
+            let voter = sc.users().get_or_create_user(&managed_address!(&voter_address));
+            let proposal = Proposal {
+                id: 1,
+                proposer: voter,
+                content_hash: managed_buffer!(b"content hash"),
+                actions_hash: ManagedBuffer::new(),
+                starts_at: 0,
+                ends_at: 60,
+                executed: false,
+                roles: ManagedVec::new(),
+            };
+
+            sc.require_payments_with_gov_token(&payments);
+            sc.commit_vote_payments(voter, &proposal, &payments);
+
+            assert_eq!(managed_biguint!(15), sc.get_vote_weight_from_payments(&payments, voter, None));
+            assert_eq!(managed_biguint!(10), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_FAKE_TOKEN_ID), 0).get());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_rejects_payments_of_deregistered_gov_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+            sc.deregister_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID));
+
+            let payments = ManagedVec::from_single_item(EsdtTokenPayment::new(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 0, managed_biguint!(5)));
+
+            sc.require_payments_with_gov_token(&payments);
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_stakes_only_the_gov_token_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&staker_address, &setup.contract, ENTITY_FAKE_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_weighs_payments_of_deregistered_gov_tokens_as_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(0));
+            sc.deregister_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID));
+
+            let payment = EsdtTokenPayment::new(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 0, managed_biguint!(5));
+
+            assert_eq!(managed_biguint!(0), sc.get_gov_token_payment_weight(&payment));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_additional_gov_tokens_toward_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(500));
+
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY + 1_000), sc.get_circulating_gov_token_supply());
+
+            sc.deregister_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID));
+
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), sc.get_circulating_gov_token_supply());
+        })
+        .assert_ok();
+}
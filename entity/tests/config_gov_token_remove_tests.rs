@@ -1,6 +1,5 @@
 use entity::config::*;
-use entity::governance::*;
-use entity::permission::*;
+use entity::governance::token::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -1,5 +1,7 @@
 use entity::config::*;
+use entity::governance::token::*;
 use entity::governance::*;
+use entity::plug::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -8,14 +10,14 @@ mod setup;
 #[test]
 fn it_configures_a_plug() {
     let mut setup = EntitySetup::new(entity::contract_obj);
-    let plug_address = setup.contract.address_ref();
+    let plug_address = setup.contract.address_ref().clone();
 
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.set_plug_endpoint(managed_address!(plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
 
-            assert_eq!(sc.quorum().get(), managed_biguint!(1000));
+            assert_eq!(sc.default_quorum().get(), managed_biguint!(1000));
             assert_eq!(sc.min_propose_weight().get(), managed_biguint!(50));
         })
         .assert_ok();
@@ -24,12 +26,12 @@ fn it_configures_a_plug() {
 #[test]
 fn it_fails_when_caller_not_self() {
     let mut setup = EntitySetup::new(entity::contract_obj);
-    let plug_address = setup.contract.address_ref();
+    let plug_address = setup.contract.address_ref().clone();
 
     setup
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.set_plug_endpoint(managed_address!(plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
         })
         .assert_user_error("action not allowed by user");
 }
@@ -37,13 +39,82 @@ fn it_fails_when_caller_not_self() {
 #[test]
 fn it_fails_when_invalid_weight_decimals() {
     let mut setup = EntitySetup::new(entity::contract_obj);
-    let plug_address = setup.contract.address_ref();
+    let plug_address = setup.contract.address_ref().clone();
     let invalid_weight_decimals = 19; // max is derived from max token decimals which is 18
 
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.set_plug_endpoint(managed_address!(plug_address), managed_biguint!(1000), managed_biguint!(50), invalid_weight_decimals);
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), invalid_weight_decimals);
         })
         .assert_user_error("invalid weight decimals");
 }
+
+#[test]
+fn it_normalizes_plug_weights_to_the_gov_token_decimals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+            sc.change_gov_token_decimals_endpoint(6);
+
+            assert_eq!(managed_biguint!(3_000_000), sc.normalize_plug_weight(&sc.plugs().get(&managed_address!(&plug_address)).unwrap(), managed_biguint!(3)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_truncates_plug_weights_with_more_decimals_than_the_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 18);
+            sc.change_gov_token_decimals_endpoint(0);
+
+            assert_eq!(managed_biguint!(2), sc.normalize_plug_weight(&sc.plugs().get(&managed_address!(&plug_address)).unwrap(), managed_biguint!(2_500_000_000_000_000_000)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_defaults_to_18_gov_token_decimals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 16);
+
+            assert_eq!(18, sc.get_gov_token_decimals_view());
+            assert_eq!(managed_biguint!(500), sc.normalize_plug_weight(&sc.plugs().get(&managed_address!(&plug_address)).unwrap(), managed_biguint!(5)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_plug_weights_without_a_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(&plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+
+            assert_eq!(managed_biguint!(3), sc.normalize_plug_weight(&sc.plugs().get(&managed_address!(&plug_address)).unwrap(), managed_biguint!(3)));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,269 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::plug::*;
+use entity::Entity;
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_adds_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(plug_address), 20_000, 6, PlugFailurePolicy::Zero);
+
+            let settings = sc.plugs().get(&managed_address!(plug_address)).unwrap();
+            assert_eq!(20_000, settings.multiplier);
+            assert_eq!(6, settings.weight_decimals);
+            assert_eq!(PlugFailurePolicy::Zero, settings.failure_policy);
+            assert!(sc.is_plugged());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_adds_the_main_plug_with_a_single_multiplier() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+
+            let settings = sc.plugs().get(&managed_address!(plug_address)).unwrap();
+            assert_eq!(PLUG_MULTIPLIER_BASE, settings.multiplier);
+            assert_eq!(PlugFailurePolicy::Abort, settings.failure_policy);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_add_a_plug_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_fails_to_add_a_plug_above_the_max_multiplier() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(plug_address), PLUG_MULTIPLIER_MAX + 1, 0, PlugFailurePolicy::Ignore);
+        })
+        .assert_user_error("max multiplier exceeded");
+}
+
+#[test]
+fn it_removes_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+            sc.remove_plug_endpoint(managed_address!(plug_address));
+
+            assert!(!sc.is_plugged());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_remove_an_unknown_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.remove_plug_endpoint(managed_address!(plug_address));
+        })
+        .assert_user_error("plug does not exist");
+}
+
+#[test]
+fn it_applies_the_plug_multiplier_to_normalized_weights() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(&plug_address), 15_000, 16, PlugFailurePolicy::Zero);
+
+            let settings = sc.plugs().get(&managed_address!(&plug_address)).unwrap();
+            assert_eq!(managed_biguint!(300), sc.normalize_plug_weight(&settings, managed_biguint!(2)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_enables_the_total_weight_reporting_of_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_plug_endpoint(managed_address!(plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+            assert!(!sc.plug_reports_total_weight(&managed_address!(plug_address)).get());
+
+            sc.set_plug_total_weight_reporting_endpoint(managed_address!(plug_address), true);
+            assert!(sc.plug_reports_total_weight(&managed_address!(plug_address)).get());
+
+            sc.remove_plug_endpoint(managed_address!(plug_address));
+            assert!(sc.plug_reports_total_weight(&managed_address!(plug_address)).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_enable_the_total_weight_reporting_of_an_unknown_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_total_weight_reporting_endpoint(managed_address!(plug_address), true);
+        })
+        .assert_user_error("plug does not exist");
+}
+
+#[test]
+fn it_registers_the_legacy_main_plug_on_upgrade() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+    let trusted_host_address = setup.trusted_host_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.plug_contract().set(managed_address!(plug_address));
+            sc.plug_weight_decimals().set(6);
+
+            sc.upgrade(managed_address!(&trusted_host_address));
+
+            let settings = sc.plugs().get(&managed_address!(plug_address)).unwrap();
+            assert_eq!(PLUG_MULTIPLIER_BASE, settings.multiplier);
+            assert_eq!(6, settings.weight_decimals);
+            assert_eq!(PlugFailurePolicy::Abort, settings.failure_policy);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_a_removed_main_plug_removed_on_upgrade() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+    let trusted_host_address = setup.trusted_host_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_plug_endpoint(managed_address!(plug_address), managed_biguint!(1000), managed_biguint!(50), 0);
+            sc.remove_plug_endpoint(managed_address!(plug_address));
+
+            sc.upgrade(managed_address!(&trusted_host_address));
+
+            assert!(!sc.is_plugged());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_clears_the_plug_votes_of_removed_plugs_when_persisting_proposal_results() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.add_plug_endpoint(managed_address!(&plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+            sc.record_plug_vote(voter, proposal.id, &managed_address!(&plug_address));
+            sc.remove_plug_endpoint(managed_address!(&plug_address));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.persist_proposal_results(1);
+
+            assert!(sc.plug_votes(1, &managed_address!(&plug_address)).is_empty());
+            assert!(sc.proposal_plugs(1).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_votes_recorded_before_the_upgrade_as_votes_with_the_main_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+    let voter_address = setup.user_address.clone();
+    let trusted_host_address = setup.trusted_host_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let voter = sc.users().get_or_create_user(&managed_address!(&voter_address));
+
+            sc.plug_contract().set(managed_address!(&plug_address));
+            sc.legacy_plug_votes(1).insert(voter);
+
+            sc.upgrade(managed_address!(&trusted_host_address));
+
+            assert!(sc.has_user_plug_voted(1, voter, &managed_address!(&plug_address)));
+            assert!(sc.has_user_plug_voted_view(1, managed_address!(&voter_address)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_reports_a_plug_vote_after_the_plug_was_removed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref().clone();
+    let voter_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let voter = sc.users().get_or_create_user(&managed_address!(&voter_address));
+
+            sc.add_plug_endpoint(managed_address!(&plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+            sc.record_plug_vote(voter, 1, &managed_address!(&plug_address));
+            sc.remove_plug_endpoint(managed_address!(&plug_address));
+
+            assert!(sc.has_user_plug_voted_view(1, managed_address!(&voter_address)));
+        })
+        .assert_ok();
+}
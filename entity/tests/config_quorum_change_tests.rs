@@ -1,5 +1,6 @@
 use entity::config::*;
 use entity::governance::*;
+use multiversx_sc::imports::OptionalValue;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -14,9 +15,9 @@ fn it_changes_the_quorum_when_contract_calls_itself() {
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.change_quorum_endpoint(managed_biguint!(1000));
+            sc.change_quorum_endpoint(managed_biguint!(1000), OptionalValue::None);
 
-            assert_eq!(sc.quorum().get(), managed_biguint!(1000));
+            assert_eq!(sc.default_quorum().get(), managed_biguint!(1000));
         })
         .assert_ok();
 }
@@ -28,7 +29,7 @@ fn it_fails_when_caller_not_self() {
     setup
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.change_quorum_endpoint(managed_biguint!(1000));
+            sc.change_quorum_endpoint(managed_biguint!(1000), OptionalValue::None);
         })
         .assert_user_error("action not allowed by user");
 }
@@ -0,0 +1,260 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::token::*;
+use entity::governance::*;
+use entity::permission::*;
+use entity::plug::*;
+use multiversx_sc::types::*;
+use multiversx_sc::imports::OptionalValue;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_quorum_to_a_percentage_of_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_fallback_endpoint(managed_biguint!(20_000));
+            sc.change_quorum_endpoint(managed_biguint!(1_000), OptionalValue::Some(QuorumMode::Percentage));
+
+            assert_eq!(managed_biguint!(1_000), sc.default_quorum().get());
+            assert_eq!(QuorumMode::Percentage, sc.default_quorum_mode().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_changes_the_quorum_to_an_absolute_value_by_default() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.default_quorum_mode().set(QuorumMode::Percentage);
+
+            sc.change_quorum_endpoint(managed_biguint!(20_000), OptionalValue::None);
+
+            assert_eq!(QuorumMode::Absolute, sc.default_quorum_mode().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_the_quorum_percentage_above_hundred_percent() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_fallback_endpoint(managed_biguint!(20_000));
+            sc.change_quorum_endpoint(managed_biguint!(QUORUM_PERCENTAGE_MAX + 1), OptionalValue::Some(QuorumMode::Percentage));
+        })
+        .assert_user_error("max quorum percentage exceeded");
+}
+
+#[test]
+fn it_fails_to_change_the_quorum_to_a_percentage_without_a_quorum_fallback() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_endpoint(managed_biguint!(1_000), OptionalValue::Some(QuorumMode::Percentage));
+        })
+        .assert_user_error("quorum fallback not set");
+}
+
+#[test]
+fn it_tracks_the_gov_token_supply_on_configuration() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), sc.gov_token_supply().get());
+            // 5% of the supply as an absolute quorum, percentage quorums are opt-in
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY * 5 / 100), sc.default_quorum().get());
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY * 5 / 100), sc.quorum_fallback().get());
+            assert_eq!(QuorumMode::Absolute, sc.default_quorum_mode().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_excludes_the_treasury_from_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .set_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(400));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+
+            // 100 of the contract balance are locked votes and therefore circulating
+            sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).set(managed_biguint!(100));
+
+            assert_eq!(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY - 300), sc.get_circulating_gov_token_supply());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_evaluates_a_percentage_quorum_against_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+
+            let role = managed_buffer!(b"testrole");
+            let proposal = Proposal {
+                id: 1,
+                proposer: 1,
+                content_hash: managed_buffer!(b"content hash"),
+                actions_hash: ManagedBuffer::new(),
+                starts_at: 0,
+                ends_at: 60,
+                executed: false,
+                roles: ManagedVec::from_single_item(role.clone()),
+            };
+
+            sc.proposal_role_info(proposal.id, &role).set(ProposalRole {
+                votes_for: managed_biguint!(50),
+                votes_against: managed_biguint!(0),
+                votes_abstain: managed_biguint!(0),
+            });
+
+            let policy = Policy {
+                method: PolicyMethod::Weight,
+                quorum: managed_biguint!(500), // 5%
+                quorum_mode: QuorumMode::Percentage,
+                voting_period_minutes: 60,
+                execution_delay_minutes: 0,
+                execution_window_minutes: 0,
+                approval_threshold: APPROVAL_THRESHOLD_DEFAULT,
+            };
+
+            assert!(sc.has_sufficient_votes(&proposal, &role, &policy));
+
+            // the supply doubles after a governance mint
+            sc.gov_token_supply().set(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY * 2));
+
+            assert!(!sc.has_sufficient_votes(&proposal, &role, &policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_falls_back_to_the_absolute_quorum_when_a_plug_does_not_report_its_total_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+            sc.add_plug_endpoint(managed_address!(plug_address), PLUG_MULTIPLIER_BASE, 0, PlugFailurePolicy::Ignore);
+            sc.change_quorum_fallback_endpoint(managed_biguint!(200));
+
+            let policy = Policy {
+                method: PolicyMethod::Weight,
+                quorum: managed_biguint!(500), // 5%
+                quorum_mode: QuorumMode::Percentage,
+                voting_period_minutes: 60,
+                execution_delay_minutes: 0,
+                execution_window_minutes: 0,
+                approval_threshold: APPROVAL_THRESHOLD_DEFAULT,
+            };
+
+            assert_eq!(managed_biguint!(200), sc.get_required_quorum(&create_proposal(), &policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_falls_back_to_the_absolute_quorum_when_the_circulating_supply_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+            sc.change_quorum_fallback_endpoint(managed_biguint!(200));
+
+            // supply of an entity upgraded from a version that did not track it
+            sc.gov_token_supply().clear();
+
+            let policy = Policy {
+                method: PolicyMethod::Weight,
+                quorum: managed_biguint!(500), // 5%
+                quorum_mode: QuorumMode::Percentage,
+                voting_period_minutes: 60,
+                execution_delay_minutes: 0,
+                execution_window_minutes: 0,
+                approval_threshold: APPROVAL_THRESHOLD_DEFAULT,
+            };
+
+            assert_eq!(managed_biguint!(200), sc.get_required_quorum(&create_proposal(), &policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_percentage_quorum_of_the_last_vote_once_voting_ended() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), true, None);
+            sc.change_quorum_fallback_endpoint(managed_biguint!(200));
+            sc.default_quorum().set(managed_biguint!(500)); // 5%
+            sc.default_quorum_mode().set(QuorumMode::Percentage);
+
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, 0);
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(50), 0);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            // the supply doubles after voting ended
+            sc.gov_token_supply().set(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY * 2));
+
+            let proposal = sc.proposals(1).get();
+            let policy = sc.policies(&managed_buffer!(ROLE_BUILTIN_MEMBER)).get(&managed_buffer!(b"testperm")).unwrap();
+
+            assert_eq!(managed_biguint!(50), sc.get_required_quorum(&proposal, &policy));
+            assert!(sc.has_sufficient_votes(&proposal, &managed_buffer!(ROLE_BUILTIN_MEMBER), &policy));
+        })
+        .assert_ok();
+}
+
+fn create_proposal() -> Proposal<DebugApi> {
+    Proposal {
+        id: 1,
+        proposer: 1,
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash: ManagedBuffer::new(),
+        starts_at: 0,
+        ends_at: 60,
+        executed: false,
+        roles: ManagedVec::new(),
+    }
+}
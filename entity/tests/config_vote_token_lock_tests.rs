@@ -1,5 +1,4 @@
 use entity::config::*;
-use entity::governance::*;
 use entity::Entity;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -7,14 +6,12 @@ use setup::*;
 mod setup;
 
 #[test]
-fn it_changes_the_vote_token_lock_when_called_by_trusted_host() {
+fn it_changes_the_vote_token_lock() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
-    setup.configure_trusted_host();
-
     setup
         .blockchain
-        .execute_tx(&setup.trusted_host_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.change_vote_token_lock_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), true);
 
             assert!(sc.lock_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID)).get());
@@ -23,7 +20,7 @@ fn it_changes_the_vote_token_lock_when_called_by_trusted_host() {
 }
 
 #[test]
-fn it_fails_when_caller_not_trusted_host() {
+fn it_fails_when_caller_not_self() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
     setup.configure_trusted_host();
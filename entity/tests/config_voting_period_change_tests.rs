@@ -14,7 +14,7 @@ fn it_changes_the_voting_period_when_contract_calls_itself() {
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.change_voting_period_in_minutes_endpoint(60);
 
-            assert_eq!(sc.voting_period_in_minutes().get(), 60);
+            assert_eq!(sc.default_voting_period_minutes().get(), 60);
         })
         .assert_ok();
 }
@@ -16,6 +16,7 @@ fn it_fails_activate_contract_when_no_code_staged() {
             sc.activate_contract_endpoint(
                 managed_buffer!(b"uniqueid1"),
                 managed_address!(contract_address),
+                managed_buffer!(b"code_hash"),
                 CodeMetadata::DEFAULT,
                 MultiValueEncoded::new(),
             );
@@ -39,6 +40,7 @@ fn it_fails_activate_contract_when_unique_id_not_unique() {
             sc.activate_contract_endpoint(
                 managed_buffer!(b"uniqueid1"),
                 managed_address!(contract_address),
+                managed_buffer!(b"code_hash"),
                 CodeMetadata::DEFAULT,
                 MultiValueEncoded::new(),
             );
@@ -46,6 +48,27 @@ fn it_fails_activate_contract_when_unique_id_not_unique() {
         .assert_user_error("unique id already exists");
 }
 
+#[test]
+fn it_fails_activate_contract_when_stage_not_locked() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let contract_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.stage(&managed_address!(contract_address)).set(managed_buffer!(b"dummy_code"));
+
+            sc.activate_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_address!(contract_address),
+                managed_buffer!(b"code_hash"),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("contract stage must be locked");
+}
+
 #[test]
 fn it_fails_when_caller_not_self() {
     let mut setup = EntitySetup::new(entity::contract_obj);
@@ -57,9 +80,92 @@ fn it_fails_when_caller_not_self() {
             sc.activate_contract_endpoint(
                 managed_buffer!(b"uniqueid"),
                 managed_address!(contract_address),
+                managed_buffer!(b"code_hash"),
                 CodeMetadata::DEFAULT,
                 MultiValueEncoded::new(),
             );
         })
         .assert_user_error("action not allowed by user");
 }
+
+#[test]
+fn it_fails_activate_contract_when_code_hash_does_not_match_approved_hash() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let contract_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.stage(&managed_address!(contract_address)).set(managed_buffer!(b"dummy_code"));
+            sc.stage_code_hash(&managed_address!(contract_address)).set(managed_buffer!(b"other_hash"));
+            sc.stage_lock(&managed_address!(contract_address)).set(true);
+
+            sc.activate_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_address!(contract_address),
+                managed_buffer!(b"approved_hash"),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("code hash mismatch");
+}
+
+#[test]
+fn it_fails_activate_contract_when_staged_code_was_swapped() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let contract_address = setup.contract.address_ref();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let approved_hash = sc.calculate_code_hash(&managed_buffer!(b"approved_code"));
+
+            sc.stage(&managed_address!(contract_address)).set(managed_buffer!(b"swapped_code"));
+            sc.stage_code_hash(&managed_address!(contract_address)).set(&approved_hash);
+            sc.stage_lock(&managed_address!(contract_address)).set(true);
+
+            sc.activate_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_address!(contract_address),
+                approved_hash,
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("staged code has been corrupted");
+}
+
+#[test]
+fn it_deploys_a_new_child_contract_when_staged_for_the_zero_address() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let code = setup.registered_contract_code(PLUG_EXAMPLE_WASM_PATH);
+
+    setup.create_plug_contract();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let code_hash = sc.calculate_code_hash(&managed_buffer!(&code));
+
+            sc.stage(&ManagedAddress::zero()).set(managed_buffer!(&code));
+            sc.stage_code_hash(&ManagedAddress::zero()).set(&code_hash);
+            sc.stage_lock(&ManagedAddress::zero()).set(true);
+
+            sc.activate_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                ManagedAddress::zero(),
+                code_hash.clone(),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+
+            let children = sc.get_child_contracts_view().into_iter().collect::<Vec<_>>();
+            let (_, child_code_hash) = children[0].clone().into_tuple();
+
+            assert_eq!(1, children.len());
+            assert_eq!(code_hash, child_code_hash);
+            assert!(sc.stage(&ManagedAddress::zero()).is_empty());
+        })
+        .assert_ok();
+}
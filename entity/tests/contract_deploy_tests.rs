@@ -0,0 +1,158 @@
+use entity::contract::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_stages_deployment_code_by_its_hash() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let dev_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+            sc.assign_role(managed_address!(&dev_address), managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+
+            let code_hash = sc.stage_deployment_endpoint(managed_buffer!(b"dummy_code"));
+
+            assert_eq!(code_hash, sc.calculate_code_hash(&managed_buffer!(b"dummy_code")));
+            assert_eq!(sc.deploy_stage(&code_hash).get(), managed_buffer!(b"dummy_code"));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_stage_deployment_by_non_developer() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let non_dev_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&non_dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.stage_deployment_endpoint(managed_buffer!(b"dummy_code"));
+        })
+        .assert_user_error("caller must be developer");
+}
+
+#[test]
+fn it_fails_to_deploy_contract_when_not_staged() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.deploy_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_buffer!(b"code_hash"),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("deployment not staged");
+}
+
+#[test]
+fn it_fails_to_deploy_contract_when_unique_id_not_unique() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.deploy_stage(&managed_buffer!(b"code_hash")).set(managed_buffer!(b"dummy_code"));
+            sc.unique_ids().insert(managed_buffer!(b"uniqueid1"));
+
+            sc.deploy_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_buffer!(b"code_hash"),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("unique id already exists");
+}
+
+#[test]
+fn it_fails_to_deploy_contract_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.deploy_contract_endpoint(
+                managed_buffer!(b"uniqueid1"),
+                managed_buffer!(b"code_hash"),
+                CodeMetadata::DEFAULT,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_lists_child_contracts_with_their_code_hash() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let child = setup.blockchain.create_sc_account(&rust_biguint!(0), Option::None, entity::contract_obj, "");
+    let child_address = child.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.child_contracts().insert(managed_address!(&child_address));
+            sc.child_contract_code_hash(&managed_address!(&child_address)).set(managed_buffer!(b"code_hash"));
+
+            let children = sc.get_child_contracts_view().into_iter().collect::<Vec<_>>();
+            let (address, code_hash) = children[0].clone().into_tuple();
+
+            assert_eq!(1, children.len());
+            assert_eq!(managed_address!(&child_address), address);
+            assert_eq!(managed_buffer!(b"code_hash"), code_hash);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_deploys_a_child_contract_from_staged_code() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let dev_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let code = setup.registered_contract_code(PLUG_EXAMPLE_WASM_PATH);
+    let mut child_address = Address::zero();
+
+    setup.create_plug_contract();
+
+    setup
+        .blockchain
+        .execute_tx(&dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+            sc.assign_role(managed_address!(&dev_address), managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+
+            sc.stage_deployment_endpoint(managed_buffer!(&code));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let code_hash = sc.calculate_code_hash(&managed_buffer!(&code));
+
+            child_address = sc
+                .deploy_contract_endpoint(managed_buffer!(b"uniqueid1"), code_hash, CodeMetadata::DEFAULT, MultiValueEncoded::new())
+                .to_address();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let children = sc.get_child_contracts_view().into_iter().collect::<Vec<_>>();
+            let (address, code_hash) = children[0].clone().into_tuple();
+
+            assert_eq!(1, children.len());
+            assert_eq!(managed_address!(&child_address), address);
+            assert_eq!(sc.calculate_code_hash(&managed_buffer!(&code)), code_hash);
+        })
+        .assert_ok();
+}
@@ -1,6 +1,7 @@
 use entity::config::*;
 use entity::contract::*;
 use entity::governance::proposal::*;
+use entity::governance::*;
 use entity::permission::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
@@ -72,11 +73,11 @@ fn it_stages_contract_and_creates_proposal_when_caller_has_permission() {
 
             sc.create_permission(
                 managed_buffer!(b"activateSc"),
-                managed_biguint!(0),
-                managed_address!(&contract_address),
-                managed_buffer!(b"stageContractAndPropose"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&contract_address)),
+                Some(managed_buffer!(b"stageContractAndPropose")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -130,11 +131,11 @@ fn it_cancels_a_previously_created_activation_proposal_when_exists() {
 
             sc.create_permission(
                 managed_buffer!(b"activateSc"),
-                managed_biguint!(0),
-                managed_address!(&contract_address),
-                managed_buffer!(b"stageContractAndPropose"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&contract_address)),
+                Some(managed_buffer!(b"stageContractAndPropose")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -209,7 +210,7 @@ fn it_fails_to_lock_stage_when_code_stage_is_empty() {
 
     setup
         .blockchain
-        .execute_tx(&setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.lock_contract_stage_endpoint(managed_address!(&contract_address));
         })
         .assert_user_error("contract stage is empty");
@@ -313,3 +314,81 @@ fn it_fails_to_stage_code_when_address_is_self() {
         })
         .assert_user_error("address must not be self");
 }
+
+#[test]
+fn it_stores_the_code_hash_and_stager_when_staging() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let dev_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let contract = setup.blockchain.create_sc_account(&rust_biguint!(0), Option::None, entity::contract_obj, "");
+    let contract_address = contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(&dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+            sc.assign_role(managed_address!(&dev_address), managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+
+            sc.stage_contract_endpoint(managed_address!(&contract_address), managed_buffer!(b"dummy_code"));
+
+            let (code_hash, stager) = sc.get_contract_stage_hash_view(managed_address!(&contract_address)).into_tuple();
+
+            assert_eq!(code_hash, sc.calculate_code_hash(&managed_buffer!(b"dummy_code")));
+            assert_eq!(stager, managed_address!(&dev_address));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_execute_a_stage_proposal_when_actions_do_not_activate_the_approved_code() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let dev_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let contract = setup.blockchain.create_sc_account(&rust_biguint!(0), Option::None, entity::contract_obj, "");
+    let contract_address = contract.address_ref().clone();
+    let entity_address = setup.contract.address_ref().clone();
+    let mut proposal_id = 0;
+
+    setup
+        .blockchain
+        .execute_tx(&dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+            sc.assign_role(managed_address!(&dev_address), managed_buffer!(ROLE_BUILTIN_DEVELOPER));
+            sc.create_permission(managed_buffer!(b"activateSc"), None, Some(managed_address!(&entity_address)), Some(managed_buffer!(b"activateContract")), None, None);
+            sc.create_policy(managed_buffer!(ROLE_BUILTIN_DEVELOPER), managed_buffer!(b"activateSc"), PolicyMethod::One, managed_biguint!(1u64), 1);
+
+            let action = Action::<DebugApi> {
+                destination: managed_address!(&entity_address),
+                endpoint: managed_buffer!(b"activateContract"),
+                arguments: ManagedVec::from(vec![managed_buffer!(b"uniqueid1"), managed_buffer!(contract_address.as_bytes()), managed_buffer!(b"other_hash")]),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            };
+
+            proposal_id = sc.stage_contract_and_propose_endpoint(
+                managed_address!(&contract_address),
+                managed_buffer!(b"new_code"),
+                managed_buffer!(b"trusted_host_id"),
+                managed_buffer!(b"content_hash"),
+                managed_buffer!(b"content_sig"),
+                sc.calculate_actions_hash(&ManagedVec::from(vec![action])),
+                MultiValueManagedVec::from(vec![managed_buffer!(b"activateSc")]),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&dev_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let action = Action::<DebugApi> {
+                destination: managed_address!(&entity_address),
+                endpoint: managed_buffer!(b"activateContract"),
+                arguments: ManagedVec::from(vec![managed_buffer!(b"uniqueid1"), managed_buffer!(contract_address.as_bytes()), managed_buffer!(b"other_hash")]),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            };
+
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(vec![action]));
+        })
+        .assert_user_error("actions must activate the approved code");
+}
@@ -0,0 +1,453 @@
+use entity::config::*;
+use entity::governance::conviction::*;
+use entity::governance::staking::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_creates_a_conviction_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let beneficiary_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &proposer_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let id = sc.propose_conviction_endpoint(
+                managed_buffer!(b"content hash"),
+                managed_address!(&beneficiary_address),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(1),
+            );
+
+            let proposal = sc.conviction_proposals(id).get();
+            assert_eq!(1, id);
+            assert_eq!(managed_address!(&beneficiary_address), proposal.beneficiary);
+            assert_eq!(managed_biguint!(1), proposal.amount);
+            assert_eq!(ConvictionStatus::Active, sc.get_conviction_status(&proposal));
+            assert_eq!(2, sc.next_conviction_proposal_id().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_create_a_conviction_proposal_without_enough_stake() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let beneficiary_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.propose_conviction_endpoint(
+                managed_buffer!(b"content hash"),
+                managed_address!(&beneficiary_address),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(1),
+            );
+        })
+        .assert_user_error("insufficient vote weight");
+}
+
+#[test]
+fn it_requires_the_conviction_weight_share_of_the_circulating_supply_as_stake_to_propose() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let beneficiary_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.configure_min_propose_weight(1);
+    set_gov_token_supply(&mut setup, 100_000);
+    stake(&mut setup, &proposer_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            // 1% of the circulating supply, which includes staked tokens
+            assert_eq!(managed_biguint!(1_000), sc.get_conviction_min_propose_weight_view());
+
+            sc.propose_conviction_endpoint(
+                managed_buffer!(b"content hash"),
+                managed_address!(&beneficiary_address),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(1),
+            );
+        })
+        .assert_user_error("insufficient vote weight");
+}
+
+#[test]
+fn it_allocates_the_stake_as_support() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.support_conviction_endpoint(1, managed_biguint!(60));
+
+            let supporter = sc.users().get_user_id(&managed_address!(&supporter_address));
+            let (support, conviction) = sc.get_conviction_view(1).into_tuple();
+            assert_eq!(managed_biguint!(60), support);
+            assert_eq!(managed_biguint!(0), conviction);
+            assert_eq!(managed_biguint!(60), sc.stake_allocations(supporter).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.support_conviction_endpoint(1, managed_biguint!(41));
+        })
+        .assert_user_error("insufficient unallocated stake");
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(41));
+        })
+        .assert_user_error("stake is allocated");
+}
+
+#[test]
+fn it_builds_up_conviction_over_time() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+    support_conviction(&mut setup, &supporter_address, 100);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS - 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let (_, conviction) = sc.get_conviction_view(1).into_tuple();
+            assert_eq!(managed_biguint!(0), conviction);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let (_, conviction) = sc.get_conviction_view(1).into_tuple();
+            assert_eq!(managed_biguint!(190), conviction);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_decays_conviction_after_the_support_was_withdrawn() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+    support_conviction(&mut setup, &supporter_address, 100);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_conviction_support_endpoint(1, managed_biguint!(100));
+
+            let supporter = sc.users().get_user_id(&managed_address!(&supporter_address));
+            assert_eq!(managed_biguint!(0), sc.stake_allocations(supporter).get());
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let (support, conviction) = sc.get_conviction_view(1).into_tuple();
+            assert_eq!(managed_biguint!(0), support);
+            assert_eq!(managed_biguint!(90), conviction);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_passes_and_executes_after_the_execution_delay_once_the_conviction_crosses_the_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+    let beneficiary_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    set_gov_token_supply(&mut setup, 1_000);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+    support_conviction(&mut setup, &supporter_address, 100);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            // requests 1% of the treasury: 1_000 * 1% / (1 - 1% / 20%)^2 / (1 - 90%)
+            assert_eq!(managed_biguint!(110), sc.get_conviction_threshold_view(1).into_option().unwrap());
+            assert_eq!(ConvictionStatus::Active, sc.get_conviction_status_view(1));
+
+            sc.queue_conviction_endpoint(1);
+        })
+        .assert_user_error("proposal has not passed");
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ConvictionStatus::Passed, sc.get_conviction_status_view(1));
+
+            sc.execute_conviction_endpoint(1);
+        })
+        .assert_user_error("proposal is not queued");
+
+    queue_conviction(&mut setup, &supporter_address);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.execute_conviction_endpoint(1);
+        })
+        .assert_user_error("proposal is still queued");
+
+    setup
+        .blockchain
+        .set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2 + CONVICTION_EXECUTION_DELAY_MINUTES_MIN as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ConvictionStatus::Queued, sc.get_conviction_status_view(1));
+
+            sc.execute_conviction_endpoint(1);
+
+            assert_eq!(ConvictionStatus::Executed, sc.get_conviction_status_view(1));
+        })
+        .assert_ok();
+
+    setup.blockchain.check_egld_balance(&beneficiary_address, &rust_biguint!(1));
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.support_conviction_endpoint(1, managed_biguint!(1));
+        })
+        .assert_user_error("proposal is closed");
+}
+
+#[test]
+fn it_vetoes_a_queued_conviction_proposal_by_a_guardian() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+    let guardian_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    set_gov_token_supply(&mut setup, 1_000);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+    support_conviction(&mut setup, &supporter_address, 100);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2);
+    queue_conviction(&mut setup, &supporter_address);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.veto_conviction_endpoint(1, managed_buffer!(b"reason"));
+        })
+        .assert_user_error("caller must be guardian");
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+
+            sc.veto_conviction_endpoint(1, managed_buffer!(b"reason"));
+
+            assert_eq!(ConvictionStatus::Vetoed, sc.get_conviction_status_view(1));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .set_block_timestamp(CONVICTION_PERIOD_SECONDS * 2 + CONVICTION_EXECUTION_DELAY_MINUTES_MIN as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.execute_conviction_endpoint(1);
+        })
+        .assert_user_error("proposal is not queued");
+}
+
+#[test]
+fn it_never_passes_without_a_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 1);
+    support_conviction(&mut setup, &supporter_address, 100);
+    set_gov_token_supply(&mut setup, 0);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * CONVICTION_PERIODS_MAX);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert!(sc.get_conviction_threshold_view(1).into_option().is_none());
+            assert_eq!(ConvictionStatus::Active, sc.get_conviction_status_view(1));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_never_passes_when_requesting_the_max_ratio_of_the_treasury() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let supporter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    set_gov_token_supply(&mut setup, 1_000);
+    stake(&mut setup, &supporter_address, 100);
+    propose_conviction(&mut setup, &supporter_address, 20);
+    support_conviction(&mut setup, &supporter_address, 100);
+
+    setup.blockchain.set_block_timestamp(CONVICTION_PERIOD_SECONDS * CONVICTION_PERIODS_MAX);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert!(sc.get_conviction_threshold_view(1).into_option().is_none());
+            assert_eq!(ConvictionStatus::Active, sc.get_conviction_status_view(1));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_cancels_a_conviction_proposal_by_the_proposer() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let other_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &proposer_address, 100);
+    propose_conviction(&mut setup, &proposer_address, 1);
+
+    setup
+        .blockchain
+        .execute_tx(&other_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.cancel_conviction_endpoint(1);
+        })
+        .assert_user_error("action not allowed by user");
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.cancel_conviction_endpoint(1);
+
+            assert_eq!(ConvictionStatus::Canceled, sc.get_conviction_status_view(1));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_the_conviction_settings_with_a_full_decay() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_conviction_settings_endpoint(CONVICTION_PARAMETER_MAX, CONVICTION_MAX_RATIO_DEFAULT, CONVICTION_WEIGHT_DEFAULT);
+        })
+        .assert_user_error("invalid conviction decay");
+}
+
+fn stake<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, staker_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_esdt_transfer(staker_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(amount), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_ok();
+}
+
+fn propose_conviction<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, proposer_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    let beneficiary_address = setup.owner_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.propose_conviction_endpoint(
+                managed_buffer!(b"content hash"),
+                managed_address!(&beneficiary_address),
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(amount),
+            );
+        })
+        .assert_ok();
+}
+
+fn support_conviction<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, supporter_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_tx(supporter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.support_conviction_endpoint(1, managed_biguint!(amount));
+        })
+        .assert_ok();
+}
+
+fn queue_conviction<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, caller_address: &Address)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_tx(caller_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.queue_conviction_endpoint(1);
+        })
+        .assert_ok();
+}
+
+fn set_gov_token_supply<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, supply: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.gov_token_supply().set(managed_biguint!(supply));
+        })
+        .assert_ok();
+}
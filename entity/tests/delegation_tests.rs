@@ -0,0 +1,357 @@
+use entity::config::*;
+use entity::governance::delegation::*;
+use entity::governance::proposal::*;
+use entity::governance::staking::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_delegates_voting_power() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+
+            assert_eq!(delegate, sc.delegates(delegator).get());
+            assert!(sc.delegators(delegate).contains(&delegator));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_delegates_voting_power_with_a_deposit() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+
+            assert_eq!(managed_biguint!(100), sc.get_delegated_tokens_view(managed_address!(&delegator_address)));
+            assert_eq!(managed_biguint!(100), sc.stakes(delegator).get());
+            assert_eq!(managed_biguint!(100), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_delegate_with_another_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_FAKE_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_fails_to_delegate_to_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegator_address));
+        })
+        .assert_user_error("can not delegate to self");
+}
+
+#[test]
+fn it_moves_the_delegation_to_a_new_delegate() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+    let new_delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&new_delegate_address));
+
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+            let new_delegate = sc.users().get_user_id(&managed_address!(&new_delegate_address));
+
+            assert!(!sc.delegators(delegate).contains(&delegator));
+            assert!(sc.delegators(new_delegate).contains(&delegator));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_delegated_weight_when_the_delegate_votes() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &delegate_address, 10);
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+
+            sc.vote(delegate, proposal, VoteType::For, managed_biguint!(25), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(125), role_info.votes_for);
+            assert_eq!(managed_biguint!(125), sc.vote_receipts(1, delegate).get().weight);
+            assert!(sc.proposal_delegate_voters(1).contains(&delegate));
+            assert_eq!(70, sc.delegated_stake_unlocks_at(delegate).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_overrides_the_delegate_when_the_delegator_votes_directly() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &delegate_address, 10);
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+
+            sc.assign_role(managed_address!(&delegator_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+
+            sc.vote(delegate, proposal, VoteType::For, managed_biguint!(25), 0);
+            sc.vote(delegator, sc.proposals(1).get(), VoteType::Against, managed_biguint!(0), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(25), role_info.votes_for);
+            assert_eq!(managed_biguint!(100), role_info.votes_against);
+            assert_eq!(managed_biguint!(25), sc.vote_receipts(1, delegate).get().weight);
+            assert_eq!(managed_biguint!(100), sc.vote_receipts(1, delegator).get().weight);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_skips_delegators_who_voted_directly() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &delegate_address, 10);
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+
+            sc.assign_role(managed_address!(&delegator_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+
+            sc.vote(delegator, proposal, VoteType::Against, managed_biguint!(0), 0);
+            sc.vote(delegate, sc.proposals(1).get(), VoteType::For, managed_biguint!(25), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(25), role_info.votes_for);
+            assert_eq!(managed_biguint!(100), role_info.votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_undelegates() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+
+            assert!(sc.delegates(delegator).is_empty());
+            assert!(!sc.delegators(delegate).contains(&delegator));
+            assert_eq!(managed_biguint!(100), sc.unbonding_stakes(delegator).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_undelegates_and_unstakes_the_deposit() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(40), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+
+            let delegator = sc.users().get_user_id(&managed_address!(&delegator_address));
+
+            assert!(sc.delegated_tokens(delegator).is_empty());
+            assert_eq!(managed_biguint!(40), sc.stakes(delegator).get());
+            assert_eq!(managed_biguint!(100), sc.unbonding_stakes(delegator).get());
+            assert_eq!(1_000 + UNBONDING_PERIOD_MINUTES_DEFAULT as u64 * 60, sc.stake_unbonds_at(delegator).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_undelegate_without_delegation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_user_error("no delegation");
+}
+
+fn stake_and_delegate<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, delegator_address: &Address, delegate_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_esdt_transfer(delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(amount), |sc| {
+            sc.delegate_endpoint(managed_address!(delegate_address));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_tracks_the_stake_delegated_to_a_delegate() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+    let new_delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup.blockchain.set_block_timestamp(20);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(50), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(30);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&new_delegate_address));
+
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+            let new_delegate = sc.users().get_user_id(&managed_address!(&new_delegate_address));
+
+            assert_eq!(managed_biguint!(100), sc.get_delegated_stake_at(delegate, 11));
+            assert_eq!(managed_biguint!(150), sc.get_delegated_stake_at(delegate, 21));
+            assert_eq!(managed_biguint!(0), sc.delegated_stakes(delegate).get());
+            assert_eq!(managed_biguint!(150), sc.delegated_stakes(new_delegate).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_unstake_delegated_stake_while_the_delegate_votes_with_it() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    stake_and_delegate(&mut setup, &delegator_address, &delegate_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &delegate_address, 10);
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+
+            sc.vote(delegate, proposal, VoteType::For, managed_biguint!(25), 0);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(100));
+        })
+        .assert_user_error("stake is locked");
+}
@@ -53,11 +53,11 @@ fn it_directly_executes_an_action_unilaterally() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"developer"));
             sc.create_permission(
                 managed_buffer!(b"sendEgld"),
-                managed_biguint!(1),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(1)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"developer"),
@@ -143,11 +143,11 @@ fn it_fails_when_caller_has_no_permissions() {
             sc.create_role(managed_buffer!(b"developer"));
             sc.create_permission(
                 managed_buffer!(b"sendEgld"),
-                managed_biguint!(1),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(1)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"developer"),
@@ -0,0 +1,167 @@
+use entity::config::*;
+use entity::governance::escrow::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const MAX_LOCK_SECONDS: u64 = ESCROW_LOCK_MINUTES_MAX as u64 * 60;
+
+#[test]
+fn it_locks_governance_tokens_in_escrow() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+
+            let (amount, unlocks_at) = sc.get_escrow_view(managed_address!(&user_address)).into_tuple();
+            assert_eq!(managed_biguint!(100), amount);
+            assert_eq!(MAX_LOCK_SECONDS, unlocks_at);
+            assert_eq!(managed_biguint!(100), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_lock_longer_than_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max lock duration exceeded");
+}
+
+#[test]
+fn it_extends_the_lock_without_payment() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.lock_escrow_endpoint(120);
+
+            let (amount, unlocks_at) = sc.get_escrow_view(managed_address!(&user_address)).into_tuple();
+            assert_eq!(managed_biguint!(100), amount);
+            assert_eq!(120 * 60, unlocks_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_decays_the_escrow_power_linearly() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1_000), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let user = managed_address!(&user_address);
+
+            assert_eq!(managed_biguint!(0), sc.get_escrow_power_at_view(user.clone(), 0));
+            assert_eq!(managed_biguint!(999), sc.get_escrow_power_at_view(user.clone(), 1));
+            assert_eq!(managed_biguint!(500), sc.get_escrow_power_at_view(user.clone(), MAX_LOCK_SECONDS / 2));
+            assert_eq!(managed_biguint!(0), sc.get_escrow_power_at_view(user, MAX_LOCK_SECONDS));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_votes_with_the_escrow_power() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1_000), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX / 2);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &user_address, 10);
+            let user = sc.users().get_user_id(&managed_address!(&user_address));
+
+            sc.vote(user, proposal, VoteType::For, managed_biguint!(0), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(499), role_info.votes_for);
+            assert_eq!(70, sc.escrow_vote_unlocks_at(user).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_withdraws_the_escrow_after_the_lock_expired() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_user_error("escrow is locked");
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+
+            assert!(sc.escrow_locks(sc.users().get_user_id(&managed_address!(&user_address))).is_empty());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
@@ -0,0 +1,217 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_expires_a_succeeded_proposal_after_the_execution_window() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_window_in_minutes_endpoint(60);
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_starts_the_execution_window_after_the_execution_delay() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+            sc.change_execution_window_in_minutes_endpoint(60);
+
+            create_signed_leader_proposal(&sc);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 90 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_applies_the_shortest_execution_window_of_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_window_in_minutes_endpoint(60);
+            sc.set_policy_execution_window_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), 10);
+
+            create_signed_leader_proposal(&sc);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 10 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_never_expires_when_no_execution_window_is_set() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            create_signed_leader_proposal(&sc);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 365 * 24 * 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_execute_an_expired_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_window_in_minutes_endpoint(60);
+
+            create_signed_leader_proposal(&sc);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let actions = MultiValueManagedVec::from(vec![dummy_action()]);
+
+            sc.execute_endpoint(1, actions);
+        })
+        .assert_user_error("proposal has expired");
+}
+
+#[test]
+fn it_starts_the_execution_window_of_a_proposal_fulfilled_without_signature_once_queued() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_window_in_minutes_endpoint(60);
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            // as if fulfilled by unassigning members instead of signing
+            sc.proposal_succeeded_at(proposal.id).clear();
+            sc.proposal_execution_limits(proposal.id).clear();
+
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(5_000);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.queue_proposal_endpoint(1);
+
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(5_000, sc.proposal_succeeded_at(1).get());
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(5_000 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_the_default_execution_window_when_exceeding_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_window_in_minutes_endpoint(EXECUTION_WINDOW_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max execution window exceeded");
+}
+
+#[test]
+fn it_fails_to_set_the_execution_window_of_a_policy_when_exceeding_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_policy_execution_window_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), EXECUTION_WINDOW_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max execution window exceeded");
+}
@@ -1,5 +1,5 @@
+use entity::permission::*;
 use entity::config::*;
-use entity::governance::errors::*;
 use entity::governance::proposal::*;
 use entity::governance::*;
 use multiversx_sc::codec::multi_types::*;
@@ -16,7 +16,9 @@ fn it_executes_actions_of_a_succeeded_proposal() {
     let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
+    allow_members_to_propose_any_action(&mut setup);
 
     setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
 
@@ -41,7 +43,6 @@ fn it_executes_actions_of_a_succeeded_proposal() {
                 });
 
                 let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-                let actions_permissions = MultiValueManagedVec::new();
 
                 sc.propose_endpoint(
                     managed_buffer!(b"id"),
@@ -49,7 +50,8 @@ fn it_executes_actions_of_a_succeeded_proposal() {
                     managed_buffer!(b"b"),
                     actions_hash,
                     POLL_DEFAULT_ID,
-                    actions_permissions,
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -86,7 +88,9 @@ fn it_fails_when_the_proposal_has_been_defeated() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
+    allow_members_to_propose_any_action(&mut setup);
 
     // proposing with minimum to propose which is less than required quorum
     setup
@@ -104,7 +108,6 @@ fn it_fails_when_the_proposal_has_been_defeated() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::new();
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -112,7 +115,8 @@ fn it_fails_when_the_proposal_has_been_defeated() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -144,7 +148,9 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
     let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
+    allow_members_to_propose_any_action(&mut setup);
 
     setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
 
@@ -169,7 +175,6 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
                 });
 
                 let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-                let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
                 sc.propose_endpoint(
                     managed_buffer!(b"id"),
@@ -177,7 +182,8 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
                     managed_buffer!(b"b"),
                     actions_hash,
                     POLL_DEFAULT_ID,
-                    actions_permissions,
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -212,7 +218,9 @@ fn it_fails_to_spend_esdt_vote_tokens() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
+    allow_members_to_propose_any_action(&mut setup);
 
     // set available balance to 5
     setup
@@ -235,7 +243,6 @@ fn it_fails_to_spend_esdt_vote_tokens() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::new();
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -243,7 +250,8 @@ fn it_fails_to_spend_esdt_vote_tokens() {
                 managed_buffer!(b"b"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -256,11 +264,11 @@ fn it_fails_to_spend_esdt_vote_tokens() {
         })
         .assert_ok();
 
-    // add to the sc token balance: vote against with 100 tokens
+    // add to the sc token balance: vote for with another 20 tokens
     setup
         .blockchain
         .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
-            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
@@ -283,5 +291,23 @@ fn it_fails_to_spend_esdt_vote_tokens() {
 
             sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
         })
-        .assert_user_error(&String::from_utf8(NOT_ENOUGH_GOV_TOKENS_AVAILABLE.to_vec()).unwrap());
+        .assert_user_error("not enough tokens available");
+}
+
+fn allow_members_to_propose_any_action<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_MEMBER),
+                managed_buffer!(PERMISSION_WILDCARD),
+                PolicyMethod::Weight,
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+        })
+        .assert_ok();
 }
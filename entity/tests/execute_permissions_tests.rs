@@ -28,11 +28,11 @@ fn it_executes_actions_when_proposed_by_non_leader() {
             sc.assign_role(managed_address!(&board_member_two), managed_buffer!(board_role));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(5),
-                managed_address!(&action_receiver),
-                managed_buffer!(b""),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(5)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                None,
             );
             sc.create_policy(managed_buffer!(board_role), managed_buffer!(b"perm"), PolicyMethod::Majority, BigUint::from(0u64), 10);
         })
@@ -54,7 +54,6 @@ fn it_executes_actions_when_proposed_by_non_leader() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -62,7 +61,8 @@ fn it_executes_actions_when_proposed_by_non_leader() {
                 managed_buffer!(b"b"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[board_role]),
             );
         })
         .assert_ok();
@@ -116,11 +116,11 @@ fn it_executes_actions_with_advanced_permissions_when_proposed_by_non_leader() {
             sc.assign_role(managed_address!(&board_member_two), managed_buffer!(board_role));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"addCategory"),
-                ManagedVec::from(vec![managed_buffer!(b"arg1")]),
-                ManagedVec::new(),
+                None,
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"addCategory")),
+                Some(ManagedVec::from(vec![managed_buffer!(b"arg1")])),
+                None,
             );
             sc.create_policy(managed_buffer!(board_role), managed_buffer!(b"perm"), PolicyMethod::Majority, BigUint::from(0u64), 10);
         })
@@ -142,7 +142,6 @@ fn it_executes_actions_with_advanced_permissions_when_proposed_by_non_leader() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -150,7 +149,8 @@ fn it_executes_actions_with_advanced_permissions_when_proposed_by_non_leader() {
                 managed_buffer!(b"b"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[board_role]),
             );
         })
         .assert_ok();
@@ -202,11 +202,11 @@ fn it_fails_when_a_required_argument_is_missing() {
 
             sc.create_permission(
                 managed_buffer!(b"callSc"),
-                managed_biguint!(10),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::from(vec![managed_buffer!(b"testarg1"), managed_buffer!(b"testarg2")]),
-                ManagedVec::new(),
+                Some(managed_biguint!(10)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                Some(ManagedVec::from(vec![managed_buffer!(b"testarg1"), managed_buffer!(b"testarg2")])),
+                None,
             );
 
             sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"callSc"), PolicyMethod::Quorum, BigUint::from(1u64), 1);
@@ -228,7 +228,6 @@ fn it_fails_when_a_required_argument_is_missing() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"callSc")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -236,7 +235,8 @@ fn it_fails_when_a_required_argument_is_missing() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -279,11 +279,11 @@ fn it_fails_when_payment_value_is_higher_than_defined_by_permission() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"sendEGLD"),
-                managed_biguint!(10),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(10)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -309,7 +309,6 @@ fn it_fails_when_payment_value_is_higher_than_defined_by_permission() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"sendEGLD")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -317,7 +316,8 @@ fn it_fails_when_payment_value_is_higher_than_defined_by_permission() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -360,11 +360,11 @@ fn it_fails_when_token_payment_amount_is_higher_than_defined_by_permission() {
 
             sc.create_permission(
                 managed_buffer!(b"sendSuper"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(b"SUPER-123456"), 0, managed_biguint!(10))]),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                Some(ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(b"SUPER-123456"), 0, managed_biguint!(10))])),
             );
 
             sc.create_policy(
@@ -391,7 +391,6 @@ fn it_fails_when_token_payment_amount_is_higher_than_defined_by_permission() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"sendSuper")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -399,7 +398,8 @@ fn it_fails_when_token_payment_amount_is_higher_than_defined_by_permission() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -1,5 +1,4 @@
 use entity::config::*;
-use entity::governance::errors::*;
 use entity::governance::proposal::*;
 use entity::governance::*;
 use entity::permission::*;
@@ -26,11 +25,11 @@ fn it_executes_actions_of_a_proposal() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(5),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(5)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -63,7 +62,6 @@ fn it_executes_actions_of_a_proposal() {
                 });
 
                 let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-                let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
                 sc.propose_endpoint(
                     managed_buffer!(b"id"),
@@ -71,7 +69,8 @@ fn it_executes_actions_of_a_proposal() {
                     managed_buffer!(b"b"),
                     actions_hash,
                     POLL_DEFAULT_ID,
-                    actions_permissions,
+                    0,
+                    roles(&[ROLE_BUILTIN_LEADER]),
                 );
             },
         )
@@ -115,11 +114,11 @@ fn it_marks_a_proposal_as_executed() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -145,7 +144,6 @@ fn it_marks_a_proposal_as_executed() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -153,7 +151,8 @@ fn it_marks_a_proposal_as_executed() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -195,11 +194,11 @@ fn it_fails_when_attempted_to_execute_again() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -225,7 +224,6 @@ fn it_fails_when_attempted_to_execute_again() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -233,7 +231,8 @@ fn it_fails_when_attempted_to_execute_again() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -279,6 +278,7 @@ fn it_fails_when_the_proposal_is_still_active() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -302,7 +302,6 @@ fn it_fails_when_the_proposal_is_still_active() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -310,7 +309,8 @@ fn it_fails_when_the_proposal_is_still_active() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -341,6 +341,7 @@ fn it_fails_when_the_proposal_has_been_defeated() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -365,7 +366,6 @@ fn it_fails_when_the_proposal_has_been_defeated() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -373,7 +373,8 @@ fn it_fails_when_the_proposal_has_been_defeated() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -415,11 +416,11 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"perm"), PolicyMethod::Quorum, BigUint::from(1u64), 10);
         })
@@ -446,7 +447,6 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
                 });
 
                 let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-                let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
                 sc.propose_endpoint(
                     managed_buffer!(b"id"),
@@ -454,7 +454,8 @@ fn it_fails_when_actions_to_execute_are_incongruent_to_actions_proposed() {
                     managed_buffer!(b"b"),
                     actions_hash,
                     POLL_DEFAULT_ID,
-                    actions_permissions,
+                    0,
+                    roles(&[b"builder"]),
                 );
             },
         )
@@ -499,11 +500,11 @@ fn it_executes_a_contract_call_action() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -536,7 +537,6 @@ fn it_executes_a_contract_call_action() {
                 });
 
                 let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-                let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
                 sc.propose_endpoint(
                     managed_buffer!(b"id"),
@@ -544,7 +544,8 @@ fn it_executes_a_contract_call_action() {
                     managed_buffer!(b"b"),
                     actions_hash,
                     POLL_DEFAULT_ID,
-                    actions_permissions,
+                    0,
+                    roles(&[ROLE_BUILTIN_LEADER]),
                 );
             },
         )
@@ -593,11 +594,11 @@ fn it_fails_to_spend_esdt_vote_tokens() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, managed_biguint!(10))]),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                Some(ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, managed_biguint!(10))])),
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -625,7 +626,6 @@ fn it_fails_to_spend_esdt_vote_tokens() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -633,7 +633,8 @@ fn it_fails_to_spend_esdt_vote_tokens() {
                 managed_buffer!(b"b"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -646,11 +647,19 @@ fn it_fails_to_spend_esdt_vote_tokens() {
         })
         .assert_ok();
 
-    // add to the sc token balance: vote against with 100 tokens
+    // add to the sc token balance: vote for with another 20 tokens
     setup
         .blockchain
         .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
-            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    // the other leader signs to fulfill the leaders' majority policy
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
@@ -673,7 +682,7 @@ fn it_fails_to_spend_esdt_vote_tokens() {
 
             sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
         })
-        .assert_user_error(&String::from_utf8(NOT_ENOUGH_GOV_TOKENS_AVAILABLE.to_vec()).unwrap());
+        .assert_user_error("not enough tokens available");
 }
 
 #[test]
@@ -701,11 +710,11 @@ fn it_fails_to_spend_sft_vote_tokens() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(ENTITY_GOV_TOKEN_ID), 1, managed_biguint!(10))]),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                Some(ManagedVec::from(vec![EsdtTokenPayment::new(managed_token_id!(ENTITY_GOV_TOKEN_ID), 1, managed_biguint!(10))])),
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -733,7 +742,6 @@ fn it_fails_to_spend_sft_vote_tokens() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -741,7 +749,8 @@ fn it_fails_to_spend_sft_vote_tokens() {
                 managed_buffer!(b"b"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -753,6 +762,14 @@ fn it_fails_to_spend_sft_vote_tokens() {
         })
         .assert_ok();
 
+    // the other leader signs to fulfill the leaders' majority policy
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
     setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
 
     // but it should FAIL because vote tokens should NOT be spendable
@@ -772,5 +789,5 @@ fn it_fails_to_spend_sft_vote_tokens() {
 
             sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
         })
-        .assert_user_error(&String::from_utf8(NOT_ENOUGH_GOV_TOKENS_AVAILABLE.to_vec()).unwrap());
+        .assert_user_error("not enough tokens available");
 }
@@ -0,0 +1,182 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_default_execution_delay() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+
+            assert_eq!(60, sc.default_execution_delay_minutes().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_the_default_execution_delay_when_exceeding_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(EXECUTION_DELAY_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max execution delay exceeded");
+}
+
+#[test]
+fn it_fails_to_change_the_default_execution_delay_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_sets_the_execution_delay_of_a_policy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_policy_execution_delay_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), 120);
+
+            let policy = sc.policies(&managed_buffer!(ROLE_BUILTIN_LEADER)).get(&managed_buffer!(PERMISSION_WILDCARD)).unwrap();
+            assert_eq!(120, policy.execution_delay_minutes);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_the_execution_delay_of_an_unknown_policy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_policy_execution_delay_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"unknown"), 120);
+        })
+        .assert_user_error("policy does not exist");
+}
+
+#[test]
+fn it_queues_a_succeeded_proposal_until_the_execution_delay_has_passed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            assert_eq!(1_000, sc.proposal_succeeded_at(proposal.id).get());
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_applies_the_longest_execution_delay_of_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(10);
+            sc.set_policy_execution_delay_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), 60);
+
+            create_signed_leader_proposal(&sc);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 10 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_execute_a_queued_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+
+            create_signed_leader_proposal(&sc);
+
+            let actions = MultiValueManagedVec::from(vec![dummy_action()]);
+
+            sc.execute_endpoint(1, actions);
+        })
+        .assert_user_error("proposal is queued for execution");
+}
+
+#[test]
+fn it_keeps_the_execution_delay_frozen_once_a_proposal_succeeded() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_in_minutes_endpoint(60);
+
+            create_signed_leader_proposal(&sc);
+
+            sc.change_execution_delay_in_minutes_endpoint(120);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(1).get();
+
+            assert_eq!((60 * 60, 0), sc.proposal_execution_limits(1).get());
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
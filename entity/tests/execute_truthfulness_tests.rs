@@ -1,3 +1,4 @@
+use multiversx_sc::codec::multi_types::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use entity::config::*;
@@ -26,19 +27,19 @@ fn it_executes_a_proposal_with_truthfully_announced_permissions() {
 
             sc.create_permission(
                 managed_buffer!(b"announced1"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint1"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint1")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"announced2"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint2"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint2")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -80,7 +81,6 @@ fn it_executes_a_proposal_with_truthfully_announced_permissions() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"announced1"), managed_buffer!(b"announced2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -88,7 +88,8 @@ fn it_executes_a_proposal_with_truthfully_announced_permissions() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -139,19 +140,19 @@ fn it_fails_to_executes_a_proposal_with_untruthfully_announced_permissions() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"announced"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint1"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint1")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"unannounced"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint2"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint2")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -161,13 +162,6 @@ fn it_fails_to_executes_a_proposal_with_untruthfully_announced_permissions() {
                 BigUint::from(1u64),
                 10,
             );
-            sc.create_policy(
-                managed_buffer!(ROLE_BUILTIN_LEADER),
-                managed_buffer!(b"unannounced"),
-                PolicyMethod::Weight,
-                BigUint::from(1u64),
-                12,
-            );
         })
         .assert_ok();
 
@@ -193,7 +187,6 @@ fn it_fails_to_executes_a_proposal_with_untruthfully_announced_permissions() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"announced")]); // not announcing the 'unannounced' permission
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -201,7 +194,23 @@ fn it_fails_to_executes_a_proposal_with_untruthfully_announced_permissions() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
+            );
+        })
+        .assert_ok();
+
+    // the other leader signs, and the 'unannounced' permission is granted after the proposal was created
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_LEADER),
+                managed_buffer!(b"unannounced"),
+                PolicyMethod::Weight,
+                BigUint::from(1u64),
+                12,
             );
         })
         .assert_ok();
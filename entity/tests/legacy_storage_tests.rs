@@ -0,0 +1,78 @@
+use entity::config::*;
+use entity::permission::*;
+use multiversx_sc::codec::{TopDecode, TopEncode};
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_decodes_policies_stored_before_the_quorum_mode_timelock_and_approval_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |_| {
+            // method, quorum and voting period as stored by earlier versions
+            let mut encoded = ManagedBuffer::<DebugApi>::new();
+            (PolicyMethod::Weight, BigUint::<DebugApi>::from(50u64), 60usize).top_encode(&mut encoded).unwrap();
+
+            let policy = Policy::<DebugApi>::top_decode(encoded).unwrap();
+
+            assert_eq!(PolicyMethod::Weight, policy.method);
+            assert_eq!(managed_biguint!(50), policy.quorum);
+            assert_eq!(60, policy.voting_period_minutes);
+            assert_eq!(QuorumMode::Absolute, policy.quorum_mode);
+            assert_eq!(0, policy.execution_delay_minutes);
+            assert_eq!(0, policy.execution_window_minutes);
+            assert_eq!(APPROVAL_THRESHOLD_DEFAULT, policy.approval_threshold);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_decodes_proposal_role_tallies_stored_before_abstain_votes() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |_| {
+            // for and against votes as stored by earlier versions
+            let mut encoded = ManagedBuffer::<DebugApi>::new();
+            (BigUint::<DebugApi>::from(20u64), BigUint::<DebugApi>::from(10u64)).top_encode(&mut encoded).unwrap();
+
+            let role_info = ProposalRole::<DebugApi>::top_decode(encoded).unwrap();
+
+            assert_eq!(managed_biguint!(20), role_info.votes_for);
+            assert_eq!(managed_biguint!(10), role_info.votes_against);
+            assert_eq!(managed_biguint!(0), role_info.votes_abstain);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_decodes_policies_stored_with_all_fields() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_permission(managed_buffer!(b"perm"), None, None, None, None, None);
+            sc.create_policy(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"perm"), PolicyMethod::Weight, managed_biguint!(50), 60);
+            sc.update_policy(&managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"perm"), |policy| {
+                policy.quorum_mode = QuorumMode::Percentage;
+                policy.execution_delay_minutes = 10;
+                policy.execution_window_minutes = 20;
+                policy.approval_threshold = 6_000;
+            });
+
+            let policy = sc.policies(&managed_buffer!(ROLE_BUILTIN_LEADER)).get(&managed_buffer!(b"perm")).unwrap();
+
+            assert_eq!(QuorumMode::Percentage, policy.quorum_mode);
+            assert_eq!(10, policy.execution_delay_minutes);
+            assert_eq!(20, policy.execution_window_minutes);
+            assert_eq!(6_000, policy.approval_threshold);
+        })
+        .assert_ok();
+}
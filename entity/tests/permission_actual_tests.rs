@@ -26,11 +26,11 @@ fn it_matches_a_permission_based_on_value_only() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"valueOnlyPerm"),
-                managed_biguint!(3),
-                ManagedAddress::zero(),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(3)),
+                None,
+                None,
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -56,7 +56,6 @@ fn it_matches_a_permission_based_on_value_only() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"valueOnlyPerm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -64,12 +63,13 @@ fn it_matches_a_permission_based_on_value_only() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -93,11 +93,11 @@ fn it_matches_a_permission_based_on_destination_only() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"addressOnlyPerm"),
-                managed_biguint!(1),
-                managed_address!(&action_receiver),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(1)),
+                Some(managed_address!(&action_receiver)),
+                None,
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -123,7 +123,6 @@ fn it_matches_a_permission_based_on_destination_only() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"addressOnlyPerm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -131,12 +130,13 @@ fn it_matches_a_permission_based_on_destination_only() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -160,11 +160,11 @@ fn it_matches_a_permission_based_on_endpoint_only() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"endpointOnlyPerm"),
-                managed_biguint!(0),
-                ManagedAddress::zero(),
-                managed_buffer!(b"someEndpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                None,
+                Some(managed_buffer!(b"someEndpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -190,7 +190,6 @@ fn it_matches_a_permission_based_on_endpoint_only() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"endpointOnlyPerm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -198,12 +197,13 @@ fn it_matches_a_permission_based_on_endpoint_only() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -227,11 +227,11 @@ fn it_matches_a_permission_based_on_arguments_only() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"argumentsOnlyPerm"),
-                managed_biguint!(0),
-                ManagedAddress::zero(),
-                ManagedBuffer::new(),
-                ManagedVec::from(vec![managed_buffer!(b"arg1"), managed_buffer!(b"arg2")]),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                None,
+                None,
+                Some(ManagedVec::from(vec![managed_buffer!(b"arg1"), managed_buffer!(b"arg2")])),
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -257,7 +257,6 @@ fn it_matches_a_permission_based_on_arguments_only() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"argumentsOnlyPerm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -265,12 +264,13 @@ fn it_matches_a_permission_based_on_arguments_only() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -294,14 +294,14 @@ fn it_matches_a_permission_based_on_payments_only() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"paymentOnlyPerm"),
-                managed_biguint!(0),
-                ManagedAddress::zero(),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::from(vec![
+                Some(managed_biguint!(0)),
+                None,
+                None,
+                None,
+                Some(ManagedVec::from(vec![
                     EsdtTokenPayment::new(managed_token_id!(b"ONE-123456"), 0, managed_biguint!(10)),
                     EsdtTokenPayment::new(managed_token_id!(b"TWO-123456"), 0, managed_biguint!(10)),
-                ]),
+                ])),
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -327,7 +327,6 @@ fn it_matches_a_permission_based_on_payments_only() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"paymentOnlyPerm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -335,12 +334,13 @@ fn it_matches_a_permission_based_on_payments_only() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -369,11 +369,11 @@ fn it_matches_a_permission_based_on_destination_and_endpoint() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"addressAndEndpoint"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -400,7 +400,6 @@ fn it_matches_a_permission_based_on_destination_and_endpoint() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"addressAndEndpoint")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -408,12 +407,13 @@ fn it_matches_a_permission_based_on_destination_and_endpoint() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(allowed);
             assert_eq!(1, permissions.len());
@@ -438,11 +438,11 @@ fn it_matches_a_permission_based_on_destination_and_endpoint_and_one_argument()
 
             sc.create_permission(
                 managed_buffer!(b"addressAndEndpoint"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::from(vec![managed_buffer!(b"arg1")]),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                Some(ManagedVec::from(vec![managed_buffer!(b"arg1")])),
+                None,
             );
 
             sc.create_policy(
@@ -470,7 +470,6 @@ fn it_matches_a_permission_based_on_destination_and_endpoint_and_one_argument()
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"addressAndEndpoint")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -478,12 +477,13 @@ fn it_matches_a_permission_based_on_destination_and_endpoint_and_one_argument()
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (actual, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (actual, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(actual);
             assert_eq!(1, permissions.len());
@@ -508,14 +508,14 @@ fn it_does_not_match_one_of_many_payments_that_exceeds_permission_max_amount() {
 
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                ManagedAddress::zero(),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::from(vec![
+                Some(managed_biguint!(0)),
+                None,
+                None,
+                None,
+                Some(ManagedVec::from(vec![
                     EsdtTokenPayment::new(managed_token_id!(b"ONE-123456"), 0, managed_biguint!(10)),
                     EsdtTokenPayment::new(managed_token_id!(b"TWO-123456"), 0, managed_biguint!(20)),
-                ]),
+                ])),
             );
             sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"perm"), PolicyMethod::All, BigUint::from(0u64), 10);
         })
@@ -538,7 +538,6 @@ fn it_does_not_match_one_of_many_payments_that_exceeds_permission_max_amount() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -546,12 +545,13 @@ fn it_does_not_match_one_of_many_payments_that_exceeds_permission_max_amount() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(!allowed);
             assert_eq!(0, permissions.len());
@@ -576,14 +576,14 @@ fn it_does_not_match_a_payment_when_there_is_no_permission_for_it() {
 
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                ManagedAddress::zero(),
-                ManagedBuffer::new(),
-                ManagedVec::new(),
-                ManagedVec::from(vec![
+                Some(managed_biguint!(0)),
+                None,
+                None,
+                None,
+                Some(ManagedVec::from(vec![
                     // ONE token payment is not declared but trying to spend it in below action
                     EsdtTokenPayment::new(managed_token_id!(b"TWO-123456"), 0, managed_biguint!(20)),
-                ]),
+                ])),
             );
             sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"perm"), PolicyMethod::All, BigUint::from(0u64), 10);
         })
@@ -606,7 +606,6 @@ fn it_does_not_match_a_payment_when_there_is_no_permission_for_it() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions.clone()));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             let proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -614,12 +613,13 @@ fn it_does_not_match_a_payment_when_there_is_no_permission_for_it() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
 
             let proposal = sc.proposals(proposal_id).get();
 
-            let (allowed, permissions) = sc.get_actions_execute_info(&proposal.proposer, &ManagedVec::from(actions), true);
+            let (allowed, permissions) = sc.get_actions_execute_info(proposal.proposer, &ManagedVec::from(actions), true);
 
             assert!(!allowed);
             assert_eq!(0, permissions.len());
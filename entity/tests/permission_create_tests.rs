@@ -1,5 +1,4 @@
 use entity::permission::*;
-use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -15,19 +14,19 @@ fn it_creates_a_permission() {
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_permission_endpoint(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"endpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"endpoint")),
+                None,
+                None,
             );
 
             assert!(sc.permissions().contains(&managed_buffer!(b"testperm")));
 
             let actual_permission_details = sc.permission_details(&managed_buffer!(b"testperm")).get();
 
-            assert_eq!(managed_address!(sc_address), actual_permission_details.destination);
-            assert_eq!(managed_buffer!(b"endpoint"), actual_permission_details.endpoint);
+            assert_eq!(Some(managed_address!(sc_address)), actual_permission_details.destination);
+            assert_eq!(Some(managed_buffer!(b"endpoint")), actual_permission_details.endpoint);
         })
         .assert_ok();
 }
@@ -40,14 +39,14 @@ fn it_fails_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_permission_endpoint(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"endpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"endpoint")),
+                None,
+                None,
             );
         })
         .assert_user_error("action not allowed by user");
@@ -1,4 +1,3 @@
-use entity::config::*;
 use entity::permission::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -12,8 +11,8 @@ fn it_fails_create_weighted_policy_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.create_policy_weighted_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), managed_biguint!(0), 0);
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_policy_weighted_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), Some(managed_biguint!(0)), Some(0), None);
         })
         .assert_user_error("action not allowed by user");
 }
@@ -25,7 +24,7 @@ fn it_fails_create_one_policy_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_policy_one_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"));
         })
         .assert_user_error("action not allowed by user");
@@ -38,7 +37,7 @@ fn it_fails_create_all_policy_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_policy_all_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"));
         })
         .assert_user_error("action not allowed by user");
@@ -51,7 +50,7 @@ fn it_fails_create_quorum_policy_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_policy_quorum_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), 2);
         })
         .assert_user_error("action not allowed by user");
@@ -64,7 +63,7 @@ fn it_fails_create_majority_policy_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_policy_majority_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"));
         })
         .assert_user_error("action not allowed by user");
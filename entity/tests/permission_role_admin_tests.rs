@@ -0,0 +1,242 @@
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_lets_a_role_admin_assign_and_unassign_members() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let coordinator_address = setup.user_address.clone();
+    let agent_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup_coordinator(&mut setup, &coordinator_address, 0, 0);
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_address), OptionalValue::None);
+
+            assert!(sc.has_role(&managed_address!(&agent_address), &managed_buffer!(b"agent")));
+
+            sc.admin_unassign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_address));
+
+            assert!(!sc.has_role(&managed_address!(&agent_address), &managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_caller_is_not_a_role_admin() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let coordinator_address = setup.user_address.clone();
+    let agent_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup_coordinator(&mut setup, &coordinator_address, 0, 0);
+
+    setup
+        .blockchain
+        .execute_tx(&agent_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_address), OptionalValue::None);
+        })
+        .assert_user_error("caller is not a role admin");
+}
+
+#[test]
+fn it_caps_the_membership_changes_per_period() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let coordinator_address = setup.user_address.clone();
+    let agent_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let agent_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup_coordinator(&mut setup, &coordinator_address, 1, 60);
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_one), OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_two), OptionalValue::None);
+        })
+        .assert_user_error("role admin change limit reached");
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_two), OptionalValue::None);
+
+            assert!(sc.has_role(&managed_address!(&agent_two), &managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_delegate_the_leader_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(ROLE_BUILTIN_LEADER), 0, 0);
+        })
+        .assert_user_error("leader role can not be delegated");
+}
+
+#[test]
+fn it_fails_to_grant_a_role_admin_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.grant_role_admin_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"agent"), 0, 0);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_revokes_a_role_admin() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let coordinator_address = setup.user_address.clone();
+    let agent_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup_coordinator(&mut setup, &coordinator_address, 0, 0);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.revoke_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"agent"));
+
+            assert_eq!(0, sc.get_role_admins_view(managed_buffer!(b"agent")).len());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&agent_address), OptionalValue::None);
+        })
+        .assert_user_error("caller is not a role admin");
+}
+
+fn setup_coordinator<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, coordinator_address: &Address, max_changes: u32, period_minutes: usize)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.assign_role_endpoint(managed_buffer!(b"coordinator"), managed_address!(coordinator_address), OptionalValue::None);
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"agent"), max_changes, period_minutes);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_delegate_a_role_inheriting_from_the_leader_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"council"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"council"), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"council"), 0, 0);
+        })
+        .assert_user_error("leader role can not be delegated");
+}
+
+#[test]
+fn it_fails_to_delegate_a_built_in_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(ROLE_BUILTIN_GUARDIAN), 0, 0);
+        })
+        .assert_user_error("built-in roles can not be delegated");
+}
+
+#[test]
+fn it_fails_to_let_a_role_administer_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"coordinator"), 0, 0);
+        })
+        .assert_user_error("role can not administer itself");
+}
+
+#[test]
+fn it_fails_to_assign_as_role_admin_after_the_role_inherits_from_the_leader_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let coordinator_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"council"));
+            sc.assign_role(managed_address!(&coordinator_address), managed_buffer!(b"coordinator"));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"council"), 0, 0);
+            sc.add_role_parent_endpoint(managed_buffer!(b"council"), managed_buffer!(ROLE_BUILTIN_LEADER));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&coordinator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.admin_assign_role_endpoint(managed_buffer!(b"council"), managed_address!(&coordinator_address), OptionalValue::None);
+        })
+        .assert_user_error("leader role can not be delegated");
+}
+
+#[test]
+fn it_fails_to_let_two_roles_administer_each_other() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"agent"), 0, 0);
+            sc.grant_role_admin_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"), 0, 0);
+        })
+        .assert_user_error("role already administers the admin role");
+}
+
+#[test]
+fn it_fails_to_let_a_role_administer_a_role_inheriting_from_a_role_that_administers_it() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"board"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"board"));
+            sc.grant_role_admin_endpoint(managed_buffer!(b"board"), managed_buffer!(b"agent"), 0, 0);
+            sc.grant_role_admin_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"), 0, 0);
+        })
+        .assert_user_error("role already administers the admin role");
+}
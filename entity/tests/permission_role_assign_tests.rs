@@ -1,5 +1,6 @@
 use entity::config::*;
 use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -15,7 +16,7 @@ fn it_assigns_a_role() {
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role_endpoint(managed_buffer!(b"testrole"));
 
-            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
+            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address), OptionalValue::None);
 
             let user_id = sc.users().get_user_id(&managed_address!(user_address));
 
@@ -33,7 +34,7 @@ fn it_creates_the_role_when_it_does_not_exist() {
     setup
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
-            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
+            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address), OptionalValue::None);
 
             let user_id = sc.users().get_user_id(&managed_address!(user_address));
 
@@ -52,10 +53,10 @@ fn it_only_increases_role_member_count_once_per_assigned_user() {
         .blockchain
         .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role_endpoint(managed_buffer!(b"testrole"));
-            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
+            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address), OptionalValue::None);
 
             // same user again
-            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
+            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address), OptionalValue::None);
 
             assert_eq!(1, sc.roles_member_amount(&managed_buffer!(b"testrole")).get());
         })
@@ -76,8 +77,8 @@ fn it_fails_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address), OptionalValue::None);
         })
         .assert_user_error("action not allowed by user");
 }
@@ -0,0 +1,235 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::{ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_assigns_a_role_with_expiry() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+
+            assert_eq!(100, sc.get_role_expires_at_view(managed_address!(&user_address), managed_buffer!(b"agent")));
+            assert!(sc.has_role(&managed_address!(&user_address), &managed_buffer!(b"agent")));
+            assert_eq!(1, sc.get_role_member_amount(&managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_assign_a_role_with_past_expiry() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+        })
+        .assert_user_error("expiry must be in the future");
+}
+
+#[test]
+fn it_stops_counting_expired_assignments() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let user_id = sc.users().get_user_id(&managed_address!(&user_address));
+
+            assert!(!sc.has_role(&managed_address!(&user_address), &managed_buffer!(b"agent")));
+            assert!(!sc.has_user_role(user_id, &managed_buffer!(b"agent")));
+            assert_eq!(0, sc.get_role_member_amount(&managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_makes_an_assignment_permanent_when_reassigned_without_expiry() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(0, sc.get_role_expires_at_view(managed_address!(&user_address), managed_buffer!(b"agent")));
+            assert!(sc.has_role(&managed_address!(&user_address), &managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_prunes_expired_roles() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let other_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&other_address), OptionalValue::Some(200));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(150);
+
+    // permissionless
+    setup
+        .blockchain
+        .execute_tx(&other_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.prune_expired_roles_endpoint(managed_buffer!(b"agent"));
+
+            let user_id = sc.users().get_user_id(&managed_address!(&user_address));
+            let other_id = sc.users().get_user_id(&managed_address!(&other_address));
+
+            assert!(!sc.user_roles(user_id).contains(&managed_buffer!(b"agent")));
+            assert!(sc.role_expires_at(user_id, &managed_buffer!(b"agent")).is_empty());
+            assert!(sc.user_roles(other_id).contains(&managed_buffer!(b"agent")));
+            assert_eq!(1, sc.roles_member_amount(&managed_buffer!(b"agent")).get());
+            assert_eq!(1, sc.role_expiring_members(&managed_buffer!(b"agent")).len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_excludes_expired_roles_from_user_roles() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_address), OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let roles = sc.get_user_roles_view(managed_address!(&user_address)).to_vec();
+
+            assert_eq!(1, roles.len());
+            assert_eq!(managed_buffer!(b"builder"), roles.get(0).clone_value());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_fulfill_policies_through_an_expired_proposer_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"agent");
+
+            sc.assign_role_endpoint(role.clone(), managed_address!(&proposer_address), OptionalValue::Some(100));
+            sc.create_policy_one_endpoint(role.clone(), managed_buffer!(PERMISSION_WILDCARD));
+
+            let proposal = Proposal {
+                id: 1,
+                proposer: sc.users().get_user_id(&managed_address!(&proposer_address)),
+                content_hash: managed_buffer!(b"content hash"),
+                actions_hash: ManagedBuffer::new(),
+                starts_at: 0,
+                ends_at: 60,
+                executed: false,
+                roles: ManagedVec::from_single_item(role.clone()),
+            };
+
+            sc.proposals(proposal.id).set(&proposal);
+            sc.proposal_details(proposal.id).set(ProposalDetails {
+                token: None,
+                plug: None,
+                quorum: managed_biguint!(0),
+                permissions: ManagedVec::from_single_item(managed_buffer!(PERMISSION_WILDCARD)),
+            });
+            sc.proposal_signers(proposal.id, &role).insert(proposal.proposer);
+
+            let (is_fulfilled, _) = sc.are_policies_fulfilled(&proposal);
+            assert!(is_fulfilled);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let (is_fulfilled, _) = sc.are_policies_fulfilled(&sc.proposals(1).get());
+            assert!(!is_fulfilled);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_assign_the_leader_role_with_expiry() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_address!(&user_address), OptionalValue::Some(100));
+        })
+        .assert_user_error("leader role can not expire");
+}
+
+#[test]
+fn it_excludes_expired_assignments_from_the_role_member_amount_view() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"agent"), managed_address!(&user_address), OptionalValue::Some(100));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(0, sc.get_role_member_amount_view(managed_buffer!(b"agent")));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,128 @@
+use entity::permission::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_inherits_the_policies_of_a_parent_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.create_permission(managed_buffer!(b"transfer"), None, None, None, None, None);
+            sc.create_policy_one_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"transfer"));
+
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"));
+
+            let policy = sc.get_role_policy(&managed_buffer!(b"agent"), &managed_buffer!(b"transfer")).unwrap();
+            assert_eq!(PolicyMethod::One, policy.method);
+
+            let permissions = sc.get_role_policy_permissions(&managed_buffer!(b"agent"));
+            assert_eq!(1, permissions.len());
+            assert_eq!(managed_buffer!(b"transfer"), permissions.get(0).clone_value());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_overrides_inherited_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.create_permission(managed_buffer!(b"transfer"), None, None, None, None, None);
+            sc.create_policy_one_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"transfer"));
+            sc.create_policy_all_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"transfer"));
+
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"));
+
+            let policy = sc.get_role_policy(&managed_buffer!(b"agent"), &managed_buffer!(b"transfer")).unwrap();
+            assert_eq!(PolicyMethod::All, policy.method);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_inherits_policies_across_multiple_levels() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"));
+
+            let policy = sc.get_role_policy(&managed_buffer!(b"agent"), &managed_buffer!(PERMISSION_WILDCARD)).unwrap();
+            assert_eq!(PolicyMethod::Majority, policy.method);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_create_a_cyclic_hierarchy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"coordinator"), managed_buffer!(b"agent"));
+        })
+        .assert_user_error("role hierarchy must not be cyclic");
+}
+
+#[test]
+fn it_fails_to_add_a_parent_role_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(ROLE_BUILTIN_LEADER));
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_removes_a_parent_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.remove_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(ROLE_BUILTIN_LEADER));
+
+            assert!(sc.get_role_policy(&managed_buffer!(b"agent"), &managed_buffer!(PERMISSION_WILDCARD)).is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_detaches_children_when_removing_a_parent_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"coordinator"));
+            sc.create_role_endpoint(managed_buffer!(b"agent"));
+            sc.add_role_parent_endpoint(managed_buffer!(b"agent"), managed_buffer!(b"coordinator"));
+            sc.remove_role_endpoint(managed_buffer!(b"coordinator"));
+
+            assert!(sc.role_parents(&managed_buffer!(b"agent")).is_empty());
+        })
+        .assert_ok();
+}
@@ -0,0 +1,164 @@
+use entity::config::*;
+use entity::permission::*;
+use entity::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_lists_the_members_of_a_role_paginated() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let user_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let user_three = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_one), OptionalValue::None);
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_two), OptionalValue::None);
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_three), OptionalValue::None);
+
+            let first_page = sc.get_role_members_view(managed_buffer!(b"builder"), 0, 2).to_vec();
+            assert_eq!(2, first_page.len());
+            assert_eq!(managed_address!(&user_one), first_page.get(0).clone_value());
+            assert_eq!(managed_address!(&user_two), first_page.get(1).clone_value());
+
+            let second_page = sc.get_role_members_view(managed_buffer!(b"builder"), 2, 2).to_vec();
+            assert_eq!(1, second_page.len());
+            assert_eq!(managed_address!(&user_three), second_page.get(0).clone_value());
+
+            assert_eq!(0, sc.get_role_members_view(managed_buffer!(b"builder"), 5, 2).len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_lists_all_members_with_their_roles() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_address), OptionalValue::None);
+
+            let members = sc.get_members_view(0, 10).into_iter().collect::<Vec<_>>();
+            assert_eq!(2, members.len());
+
+            let (leader, leader_roles) = members[0].clone().into_tuple();
+            assert_eq!(managed_address!(&owner_address), leader);
+            assert_eq!(managed_buffer!(ROLE_BUILTIN_LEADER), leader_roles.get(0).clone_value());
+
+            let (user, user_roles) = members[1].clone().into_tuple();
+            assert_eq!(managed_address!(&user_address), user);
+            assert_eq!(managed_buffer!(b"builder"), user_roles.get(0).clone_value());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_removes_unassigned_users_from_the_member_index() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_address), OptionalValue::None);
+            sc.unassign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_address));
+
+            let user_id = sc.users().get_user_id(&managed_address!(&user_address));
+
+            assert!(!sc.role_members(&managed_buffer!(b"builder")).contains(&user_id));
+            assert!(!sc.members().contains(&user_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_clears_the_member_index_when_removing_a_role() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&user_address), OptionalValue::None);
+            sc.assign_role_endpoint(managed_buffer!(b"other"), managed_address!(&user_address), OptionalValue::None);
+            sc.remove_role_endpoint(managed_buffer!(b"builder"));
+
+            let user_id = sc.users().get_user_id(&managed_address!(&user_address));
+
+            assert!(sc.role_members(&managed_buffer!(b"builder")).is_empty());
+            assert!(sc.members().contains(&user_id));
+            assert!(sc.user_roles(user_id).contains(&managed_buffer!(b"other")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_unassigns_all_leaders_when_becoming_leaderless() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_leaderless_mode_endpoint();
+
+            let owner_id = sc.users().get_user_id(&managed_address!(&owner_address));
+
+            assert!(sc.is_leaderless());
+            assert!(!sc.user_roles(owner_id).contains(&managed_buffer!(ROLE_BUILTIN_LEADER)));
+            assert!(!sc.members().contains(&owner_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_indexes_the_members_of_roles_assigned_before_the_index_on_upgrade() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let trusted_host_address = setup.trusted_host_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let owner_id = sc.users().get_user_id(&managed_address!(&owner_address));
+            let leader_role = managed_buffer!(ROLE_BUILTIN_LEADER);
+
+            // storage as left by versions without the index
+            sc.role_members(&leader_role).swap_remove(&owner_id);
+            sc.members().swap_remove(&owner_id);
+            sc.role_members_indexed().clear();
+
+            sc.upgrade(managed_address!(&trusted_host_address));
+
+            assert!(sc.role_members(&leader_role).contains(&owner_id));
+            assert!(sc.members().contains(&owner_id));
+            assert!(sc.role_members_indexed().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_become_leaderless_before_role_members_are_indexed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.role_members_indexed().clear();
+
+            sc.set_leaderless_mode_endpoint();
+        })
+        .assert_user_error("role members not indexed");
+}
@@ -0,0 +1,137 @@
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_binds_a_role_to_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"club"));
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::WeightView);
+
+            let role_plug = sc.role_plug(&managed_buffer!(b"club")).get();
+            assert_eq!(managed_address!(&plug_address), role_plug.plug);
+            assert_eq!(RolePlugLookup::WeightView, role_plug.lookup);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_bind_a_role_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::WeightView);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_fails_to_bind_a_role_that_does_not_exist() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::WeightView);
+        })
+        .assert_user_error("role does not exist");
+}
+
+#[test]
+fn it_binds_a_role_to_the_member_list_of_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"club"));
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::MemberList);
+
+            assert_eq!(RolePlugLookup::MemberList, sc.role_plug(&managed_buffer!(b"club")).get().lookup);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_assigned_members_of_plug_bound_roles() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"club"), managed_address!(&user_address), OptionalValue::None);
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::MemberList);
+
+            assert!(sc.has_role(&managed_address!(&user_address), &managed_buffer!(b"club")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_unbinds_a_role_from_a_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"club"));
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::WeightView);
+            sc.unbind_role_plug_endpoint(managed_buffer!(b"club"));
+
+            assert!(sc.role_plug(&managed_buffer!(b"club")).is_empty());
+            assert!(!sc.has_role(&managed_address!(&user_address), &managed_buffer!(b"club")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_only_assigned_members_of_roles_bound_to_a_weight_view() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let plug_address = setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"club"), managed_address!(&user_address), OptionalValue::None);
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::WeightView);
+
+            assert_eq!(1, sc.get_role_member_amount(&managed_buffer!(b"club")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_bind_a_role_to_a_plug_in_another_shard() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut plug_bytes = setup.contract.address_ref().to_vec();
+    let last = plug_bytes.len() - 1;
+    plug_bytes[last] = plug_bytes[last].wrapping_add(1);
+    let plug_address = Address::from_slice(&plug_bytes);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role_endpoint(managed_buffer!(b"club"));
+            sc.bind_role_plug_endpoint(managed_buffer!(b"club"), managed_address!(&plug_address), RolePlugLookup::MemberList);
+        })
+        .assert_user_error("plug must be in the same shard");
+}
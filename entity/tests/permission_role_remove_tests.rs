@@ -40,7 +40,7 @@ fn it_fails_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.remove_role_endpoint(managed_buffer!(b"testrole"));
         })
         .assert_user_error("action not allowed by user");
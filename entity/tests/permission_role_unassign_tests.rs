@@ -12,7 +12,7 @@ fn it_unassigns_a_role() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"testrole"));
 
             // TODO: switch to endpoint, currently a bug in wasm-rs lib when SC calls itself
@@ -58,7 +58,7 @@ fn it_only_decreases_role_member_count_once_per_unassigned_user() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.unassign_role(managed_address!(user_address), managed_buffer!(b"testrole"));
 
@@ -83,7 +83,7 @@ fn it_fails_when_caller_not_self() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"testrole"));
 
             sc.unassign_role_endpoint(managed_buffer!(b"testrole"), managed_address!(user_address));
@@ -98,7 +98,7 @@ fn it_fails_to_unassign_last_leader_when_not_gov_token_is_set() {
 
     setup
         .blockchain
-        .execute_tx(&setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.unassign_role_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_address!(&owner_address));
         })
         .assert_user_error("can not remove last leader: gov token or plug required");
@@ -107,7 +107,7 @@ fn it_fails_to_unassign_last_leader_when_not_gov_token_is_set() {
 
     setup
         .blockchain
-        .execute_tx(&setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.unassign_role_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_address!(&owner_address));
         })
         .assert_ok();
@@ -120,7 +120,7 @@ fn it_fails_to_unassign_last_leader_when_not_plugged() {
 
     setup
         .blockchain
-        .execute_tx(&setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.unassign_role_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_address!(&owner_address));
         })
         .assert_user_error("can not remove last leader: gov token or plug required");
@@ -129,7 +129,7 @@ fn it_fails_to_unassign_last_leader_when_not_plugged() {
 
     setup
         .blockchain
-        .execute_tx(&setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.unassign_role_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_address!(&owner_address));
         })
         .assert_ok();
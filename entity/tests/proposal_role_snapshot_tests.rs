@@ -0,0 +1,125 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc::imports::OptionalValue;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_snapshots_the_role_member_amounts_of_a_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let member_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
+            sc.assign_role(managed_address!(&member_address), managed_buffer!(b"builder"));
+
+            let proposal = create_proposal_for_role(&sc, &proposer_address, b"builder");
+            let snapshot = sc.proposal_role_member_amounts(proposal.id);
+
+            assert_eq!(1, snapshot.len());
+            assert_eq!(Some(2), snapshot.get(&managed_buffer!(b"builder")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_skips_expired_roles_of_the_proposer_in_the_snapshot() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
+            sc.assign_role_endpoint(managed_buffer!(b"expired"), managed_address!(&proposer_address), OptionalValue::Some(5));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_proposal_for_role(&sc, &proposer_address, b"builder");
+
+            assert!(!sc.proposal_role_member_amounts(proposal.id).contains_key(&managed_buffer!(b"expired")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_signer_majority_when_members_are_assigned_mid_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let member_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let member_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"builder");
+            sc.assign_role(managed_address!(&proposer_address), role.clone());
+
+            let proposal = create_proposal_for_role(&sc, &proposer_address, b"builder");
+            sc.proposal_signers(proposal.id, &role).insert(proposal.proposer);
+
+            // assigned after the proposal was created
+            sc.assign_role(managed_address!(&member_one), role.clone());
+            sc.assign_role(managed_address!(&member_two), role.clone());
+
+            assert_eq!(1, sc.get_proposal_role_member_amount(proposal.id, &role));
+            assert_eq!(3, sc.get_role_member_amount(&role));
+            assert!(sc.has_signer_majority_for_role(&proposal, &role));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_falls_back_to_the_live_member_amount_for_roles_not_in_the_snapshot() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
+
+            let proposal = create_proposal_for_role(&sc, &proposer_address, b"builder");
+
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"other"));
+
+            assert_eq!(1, sc.get_proposal_role_member_amount(proposal.id, &managed_buffer!(b"other")));
+        })
+        .assert_ok();
+}
+
+fn create_proposal_for_role(sc: &entity::ContractObj<DebugApi>, proposer_address: &Address, role: &[u8]) -> Proposal<DebugApi> {
+    let proposal = Proposal {
+        id: 1,
+        proposer: sc.users().get_user_id(&managed_address!(proposer_address)),
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash: ManagedBuffer::new(),
+        starts_at: 10,
+        ends_at: 70,
+        executed: false,
+        roles: ManagedVec::from_single_item(managed_buffer!(role)),
+    };
+
+    sc.proposals(proposal.id).set(&proposal);
+    sc.proposal_details(proposal.id).set(ProposalDetails {
+        token: None,
+        plug: None,
+        quorum: managed_biguint!(0),
+        permissions: ManagedVec::from_single_item(managed_buffer!(PERMISSION_WILDCARD)),
+    });
+    sc.snapshot_role_member_amounts(&proposal);
+
+    proposal
+}
@@ -17,6 +17,7 @@ fn it_returns_active_when_just_created() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup.blockchain.set_block_timestamp(0);
@@ -35,7 +36,6 @@ fn it_returns_active_when_just_created() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -43,7 +43,8 @@ fn it_returns_active_when_just_created() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -65,6 +66,7 @@ fn it_returns_defeated_when_for_votes_quorum_not_met() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup
@@ -81,7 +83,6 @@ fn it_returns_defeated_when_for_votes_quorum_not_met() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -89,7 +90,8 @@ fn it_returns_defeated_when_for_votes_quorum_not_met() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -118,6 +120,7 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup
@@ -134,7 +137,6 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -142,7 +144,8 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -156,7 +159,7 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
 
     setup
         .blockchain
-        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM * 2), |sc| {
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM * 2), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
@@ -178,6 +181,7 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup
@@ -194,7 +198,6 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -202,7 +205,8 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -216,7 +220,7 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
 
     setup
         .blockchain
-        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
@@ -246,11 +250,11 @@ fn it_returns_executed_for_an_executed_proposal() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -279,7 +283,6 @@ fn it_returns_executed_for_an_executed_proposal() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -287,7 +290,8 @@ fn it_returns_executed_for_an_executed_proposal() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -16,6 +16,7 @@ fn it_returns_active_for_a_newly_created_proposal() {
     let proposal_id = 1;
 
     setup.configure_plug(100, 50);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -26,7 +27,8 @@ fn it_returns_active_for_a_newly_created_proposal() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -49,6 +51,9 @@ fn it_returns_defeated_when_quorum_not_met() {
     let proposal_id = 1;
 
     setup.configure_plug(500, 50);
+    setup.configure_member_voting();
+    setup.assign_member(&voter_one);
+    setup.assign_member(&voter_two);
 
     // propose with 100 votes
     setup
@@ -60,7 +65,8 @@ fn it_returns_defeated_when_quorum_not_met() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -79,9 +85,9 @@ fn it_returns_defeated_when_quorum_not_met() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(200), proposal.votes_for);
+            assert_eq!(managed_biguint!(200), role_info.votes_for);
             assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
         })
         .assert_ok();
@@ -96,6 +102,10 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
     let proposal_id = 1;
 
     setup.configure_plug(100, 50);
+    setup.configure_member_voting();
+    setup.assign_member(&voter_one);
+    setup.assign_member(&voter_two);
+    setup.assign_member(&voter_three);
 
     // propose FOR with 100 votes
     setup
@@ -107,7 +117,8 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -133,10 +144,10 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(100), proposal.votes_for);
-            assert_eq!(managed_biguint!(200), proposal.votes_against);
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
+            assert_eq!(managed_biguint!(200), role_info.votes_against);
             assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
         })
         .assert_ok();
@@ -151,6 +162,10 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
     let proposal_id = 1;
 
     setup.configure_plug(10, 50);
+    setup.configure_member_voting();
+    setup.assign_member(&voter_one);
+    setup.assign_member(&voter_two);
+    setup.assign_member(&voter_three);
 
     // propose FOR with 100 votes
     setup
@@ -162,7 +177,8 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -188,10 +204,10 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(200), proposal.votes_for);
-            assert_eq!(managed_biguint!(100), proposal.votes_against);
+            assert_eq!(managed_biguint!(200), role_info.votes_for);
+            assert_eq!(managed_biguint!(100), role_info.votes_against);
             assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
         })
         .assert_ok();
@@ -212,11 +228,11 @@ fn it_returns_executed_for_an_executed_proposal() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -242,7 +258,6 @@ fn it_returns_executed_for_an_executed_proposal() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -250,7 +265,8 @@ fn it_returns_executed_for_an_executed_proposal() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
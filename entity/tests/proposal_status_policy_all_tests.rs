@@ -26,11 +26,11 @@ fn it_returns_active_when_just_created() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -59,7 +59,6 @@ fn it_returns_active_when_just_created() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -67,7 +66,8 @@ fn it_returns_active_when_just_created() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -97,11 +97,11 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -130,7 +130,6 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -138,7 +137,8 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -191,11 +191,11 @@ fn it_returns_defeated_when_one_of_one_permission_policies_do_not_meet_quorum_af
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -225,7 +225,6 @@ fn it_returns_defeated_when_one_of_one_permission_policies_do_not_meet_quorum_af
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -233,7 +232,8 @@ fn it_returns_defeated_when_one_of_one_permission_policies_do_not_meet_quorum_af
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
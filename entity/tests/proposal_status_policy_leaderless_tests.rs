@@ -28,19 +28,19 @@ fn it_succeeds_early_when_all_policies_are_met() {
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(1),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(1)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -78,7 +78,6 @@ fn it_succeeds_early_when_all_policies_are_met() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -86,7 +85,8 @@ fn it_succeeds_early_when_all_policies_are_met() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -130,6 +130,7 @@ fn it_succeeds_with_token_voting_when_no_policies_exist_for_a_role() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup
@@ -157,7 +158,6 @@ fn it_succeeds_with_token_voting_when_no_policies_exist_for_a_role() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::new();
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -165,7 +165,8 @@ fn it_succeeds_with_token_voting_when_no_policies_exist_for_a_role() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -199,19 +200,19 @@ fn it_returns_defeated_when_not_all_policies_are_met() {
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(1),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(1)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             // All role members sign, so this policy is met
@@ -252,7 +253,6 @@ fn it_returns_defeated_when_not_all_policies_are_met() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -260,7 +260,8 @@ fn it_returns_defeated_when_not_all_policies_are_met() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
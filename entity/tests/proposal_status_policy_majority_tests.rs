@@ -24,11 +24,11 @@ fn it_returns_active_when_just_created() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -57,7 +57,6 @@ fn it_returns_active_when_just_created() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -65,7 +64,8 @@ fn it_returns_active_when_just_created() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -94,11 +94,11 @@ fn it_succeeds_when_majority_reached() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -127,7 +127,6 @@ fn it_succeeds_when_majority_reached() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -135,7 +134,8 @@ fn it_succeeds_when_majority_reached() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -182,11 +182,11 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_reach_majori
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -216,7 +216,6 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_reach_majori
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -224,7 +223,8 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_reach_majori
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
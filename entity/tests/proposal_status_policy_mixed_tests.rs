@@ -27,19 +27,19 @@ fn it_returns_defeated_when_one_of_two_policies_do_not_meet_quorum() {
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -77,7 +77,6 @@ fn it_returns_defeated_when_one_of_two_policies_do_not_meet_quorum() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -85,7 +84,8 @@ fn it_returns_defeated_when_one_of_two_policies_do_not_meet_quorum() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
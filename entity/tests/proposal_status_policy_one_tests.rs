@@ -21,11 +21,11 @@ fn it_returns_succeeded_when_just_created_but_only_required_proposers_signature(
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -52,7 +52,6 @@ fn it_returns_succeeded_when_just_created_but_only_required_proposers_signature(
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -60,7 +59,8 @@ fn it_returns_succeeded_when_just_created_but_only_required_proposers_signature(
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -86,11 +86,11 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -118,7 +118,6 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -126,7 +125,8 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -155,19 +155,19 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -204,7 +204,6 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -212,7 +211,8 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
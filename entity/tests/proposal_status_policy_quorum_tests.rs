@@ -22,11 +22,11 @@ fn it_returns_active_when_just_created() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -53,7 +53,6 @@ fn it_returns_active_when_just_created() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -61,7 +60,8 @@ fn it_returns_active_when_just_created() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -90,11 +90,11 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -123,7 +123,6 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -131,7 +130,8 @@ fn it_succeeds_when_one_of_one_permission_policies_reaches_signer_quorum() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -183,11 +183,11 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum_
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -215,7 +215,6 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum_
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -223,7 +222,8 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum_
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -261,19 +261,19 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -309,7 +309,6 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -317,7 +316,8 @@ fn it_returns_defeated_when_one_of_two_permission_policies_does_not_meet_quorum_
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -0,0 +1,114 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_creates_a_weighted_policy_with_the_default_approval_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(managed_buffer!(b"testperm"), None, None, None, None, None);
+            sc.create_policy_weighted_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), None, None, None);
+
+            let policy = sc.policies(&managed_buffer!(b"testrole")).get(&managed_buffer!(b"testperm")).unwrap();
+            assert_eq!(APPROVAL_THRESHOLD_DEFAULT, policy.approval_threshold);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_creates_a_weighted_policy_with_a_custom_approval_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(managed_buffer!(b"testperm"), None, None, None, None, None);
+            sc.create_policy_weighted_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), None, None, Some(6_667));
+
+            let policy = sc.policies(&managed_buffer!(b"testrole")).get(&managed_buffer!(b"testperm")).unwrap();
+            assert_eq!(6_667, policy.approval_threshold);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_an_approval_threshold_above_hundred_percent() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_policy_approval_threshold_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), APPROVAL_THRESHOLD_MAX + 1);
+        })
+        .assert_user_error("max approval threshold exceeded");
+}
+
+#[test]
+fn it_fails_to_set_a_zero_approval_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_policy_approval_threshold_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(PERMISSION_WILDCARD), 0);
+        })
+        .assert_user_error("approval threshold must be greater than zero");
+}
+
+#[test]
+fn it_requires_votes_for_to_reach_the_approval_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let proposal = Proposal {
+                id: 1,
+                proposer: 1,
+                content_hash: managed_buffer!(b"content hash"),
+                actions_hash: ManagedBuffer::new(),
+                starts_at: 0,
+                ends_at: 60,
+                executed: false,
+                roles: ManagedVec::from_single_item(role.clone()),
+            };
+
+            sc.proposal_role_info(proposal.id, &role).set(ProposalRole {
+                votes_for: managed_biguint!(66),
+                votes_against: managed_biguint!(34),
+                votes_abstain: managed_biguint!(0),
+            });
+
+            let mut policy = Policy {
+                method: PolicyMethod::Weight,
+                quorum: managed_biguint!(1),
+                quorum_mode: QuorumMode::Absolute,
+                voting_period_minutes: 60,
+                execution_delay_minutes: 0,
+                execution_window_minutes: 0,
+                approval_threshold: 6_667,
+            };
+
+            assert!(!sc.has_sufficient_votes(&proposal, &role, &policy));
+
+            policy.approval_threshold = 6_600;
+
+            assert!(sc.has_sufficient_votes(&proposal, &role, &policy));
+        })
+        .assert_ok();
+}
@@ -23,11 +23,11 @@ fn it_returns_active_when_just_created() {
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -54,7 +54,6 @@ fn it_returns_active_when_just_created() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -62,7 +61,8 @@ fn it_returns_active_when_just_created() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -90,11 +90,11 @@ fn it_succeeds_when_one_of_one_permission_policies_meets_quorum_and_passed_votin
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -121,7 +121,6 @@ fn it_succeeds_when_one_of_one_permission_policies_meets_quorum_and_passed_votin
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -129,7 +128,8 @@ fn it_succeeds_when_one_of_one_permission_policies_meets_quorum_and_passed_votin
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -159,11 +159,11 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum(
             sc.create_role(managed_buffer!(b"testrole"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"testrole"),
@@ -191,7 +191,6 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum(
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -199,7 +198,8 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum(
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -22,6 +22,8 @@ fn it_requires_signer_majority_when_proposer_has_role_and_with_actions_that_do_n
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"builder"));
+            sc.create_permission(managed_buffer!(b"myperm"), None, Some(managed_address!(&action_receiver)), Some(managed_buffer!(b"myendpoint")), None, None);
+            sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_one), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_inactive), managed_buffer!(b"builder"));
@@ -49,7 +51,8 @@ fn it_requires_signer_majority_when_proposer_has_role_and_with_actions_that_do_n
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -77,7 +80,7 @@ fn it_requires_signer_majority_when_proposer_has_role_and_with_actions_that_do_n
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert_eq!(2, sc.get_signer_majority_for_role(&managed_buffer!(b"builder")));
+            assert_eq!(3, sc.get_role_member_amount(&managed_buffer!(b"builder")));
             assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
         })
         .assert_ok();
@@ -96,6 +99,8 @@ fn it_fails_when_signer_majority_not_met_when_proposer_has_role_and_with_actions
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"builder"));
+            sc.create_permission(managed_buffer!(b"myperm"), None, Some(managed_address!(&action_receiver)), Some(managed_buffer!(b"myendpoint")), None, None);
+            sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_inactive_one), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_inactive_two), managed_buffer!(b"builder"));
@@ -123,7 +128,8 @@ fn it_fails_when_signer_majority_not_met_when_proposer_has_role_and_with_actions
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -154,6 +160,9 @@ fn it_requires_signer_majority_for_multiple_roles() {
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"builder"));
             sc.create_role(managed_buffer!(b"dev"));
+            sc.create_permission(managed_buffer!(b"myperm"), None, Some(managed_address!(&action_receiver)), Some(managed_buffer!(b"myendpoint")), None, None);
+            sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
+            sc.create_policy(managed_buffer!(b"dev"), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
 
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer), managed_buffer!(b"builder"));
@@ -187,7 +196,8 @@ fn it_requires_signer_majority_for_multiple_roles() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[b"builder", b"dev"]),
             );
         })
         .assert_ok();
@@ -224,7 +234,7 @@ fn it_requires_signer_majority_for_multiple_roles() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert_eq!(2, sc.get_signer_majority_for_role(&managed_buffer!(b"builder")));
+            assert_eq!(2, sc.get_role_member_amount(&managed_buffer!(b"builder")));
             assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
         })
         .assert_ok();
@@ -243,6 +253,8 @@ fn it_succeeds_early_when_has_all_required_signatures_for_proposal_with_actions(
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"builder"));
+            sc.create_permission(managed_buffer!(b"myperm"), None, Some(managed_address!(&action_receiver)), Some(managed_buffer!(b"myendpoint")), None, None);
+            sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_one), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_inactive), managed_buffer!(b"builder"));
@@ -270,7 +282,8 @@ fn it_succeeds_early_when_has_all_required_signatures_for_proposal_with_actions(
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -302,6 +315,8 @@ fn it_returns_executed_for_an_executed_proposal_with_signer_quorum() {
     setup
         .blockchain
         .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_permission(managed_buffer!(b"myperm"), None, Some(managed_address!(&action_receiver)), Some(managed_buffer!(b"myendpoint")), None, None);
+            sc.create_policy(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"myperm"), PolicyMethod::Majority, BigUint::zero(), VOTING_PERIOD_MINUTES_DEFAULT);
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.assign_role(managed_address!(&signer_one), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.assign_role(managed_address!(&signer_inactive), managed_buffer!(ROLE_BUILTIN_LEADER));
@@ -329,7 +344,8 @@ fn it_returns_executed_for_an_executed_proposal_with_signer_quorum() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
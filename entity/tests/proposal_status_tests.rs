@@ -16,6 +16,7 @@ fn it_returns_active_for_a_newly_created_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup.blockchain.set_block_timestamp(0);
 
@@ -28,7 +29,8 @@ fn it_returns_active_for_a_newly_created_proposal() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -49,6 +51,7 @@ fn it_returns_defeated_when_for_votes_quorum_not_met() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -59,7 +62,8 @@ fn it_returns_defeated_when_for_votes_quorum_not_met() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -87,6 +91,7 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -97,7 +102,8 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -111,7 +117,7 @@ fn it_returns_defeated_when_quorum_met_but_votes_against_is_more_than_for() {
 
     setup
         .blockchain
-        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM * 2), |sc| {
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM * 2), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
@@ -132,6 +138,7 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -142,7 +149,8 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -156,7 +164,7 @@ fn it_returns_succeeded_when_for_votes_quorum_met_and_more_for_than_against_vote
 
     setup
         .blockchain
-        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
@@ -186,11 +194,11 @@ fn it_returns_executed_for_an_executed_proposal() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
             sc.create_permission(
                 managed_buffer!(b"perm"),
-                managed_biguint!(0),
-                managed_address!(&action_receiver),
-                managed_buffer!(b"myendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&action_receiver)),
+                Some(managed_buffer!(b"myendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(ROLE_BUILTIN_LEADER),
@@ -216,7 +224,6 @@ fn it_returns_executed_for_an_executed_proposal() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -224,7 +231,8 @@ fn it_returns_executed_for_an_executed_proposal() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_ok();
@@ -259,9 +267,9 @@ fn it_returns_executed_for_an_executed_proposal() {
 #[test]
 fn it_returns_canceled_when_ends_at_is_zero() {
     let mut setup = EntitySetup::new(entity::contract_obj);
-    let proposer_address = setup.blockchain.create_user_account(&rust_biguint!(1));
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -272,7 +280,8 @@ fn it_returns_canceled_when_ends_at_is_zero() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
 
             // set to zero
@@ -1,3 +1,4 @@
+use entity::permission::*;
 use entity::config::*;
 use entity::governance::proposal::{Action, ProposalModule};
 use entity::governance::*;
@@ -15,6 +16,7 @@ fn it_creates_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -25,7 +27,8 @@ fn it_creates_a_proposal() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -35,28 +38,36 @@ fn it_creates_a_proposal() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
             // proposal
             assert_eq!(1, proposal.id);
-            assert_eq!(managed_address!(&owner_address), proposal.proposer);
+            assert_eq!(sc.users().get_user_id(&managed_address!(&owner_address)), proposal.proposer);
             assert_eq!(managed_buffer!(b"content hash"), proposal.content_hash);
             assert_eq!(ManagedBuffer::new(), proposal.actions_hash);
-            assert_eq!(false, proposal.executed);
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            assert!(!proposal.executed);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
 
             // storage
             assert_eq!(2, sc.next_proposal_id().get());
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&owner_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&owner_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.payment.amount);
 
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&owner_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&owner_address))).contains(&proposal.id));
+
+            // receipt
+            let receipt = sc.vote_receipts(proposal.id, proposal.proposer).get();
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), receipt.weight);
+            assert_eq!(1, receipt.roles.len());
+            assert_eq!(managed_buffer!(ROLE_BUILTIN_MEMBER), receipt.roles.get(0).role);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), receipt.roles.get(0).weight);
         })
         .assert_ok();
 }
@@ -66,6 +77,7 @@ fn it_creates_a_proposal_with_poll() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -84,7 +96,8 @@ fn it_creates_a_proposal_with_poll() {
                     managed_buffer!(b"content signature"),
                     ManagedBuffer::new(),
                     poll_option_id,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
 
                 assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), sc.proposal_poll(proposal_id, poll_option_id).get());
@@ -101,6 +114,7 @@ fn it_creates_a_proposal_with_actions() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -124,7 +138,6 @@ fn it_creates_a_proposal_with_actions() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"any")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -132,7 +145,8 @@ fn it_creates_a_proposal_with_actions() {
                 managed_buffer!(b"content signature"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -165,6 +179,7 @@ fn it_fails_when_bad_token() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -181,7 +196,8 @@ fn it_fails_when_bad_token() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -193,6 +209,7 @@ fn it_fails_when_bad_vote_weight_amount() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -209,7 +226,8 @@ fn it_fails_when_bad_vote_weight_amount() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -221,6 +239,7 @@ fn it_fails_when_trusted_host_id_is_already_known() {
     let mut setup = EntitySetup::new(entity::contract_obj);
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -237,7 +256,8 @@ fn it_fails_when_trusted_host_id_is_already_known() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -258,7 +278,8 @@ fn it_fails_when_trusted_host_id_is_already_known() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -273,6 +294,7 @@ fn it_fails_when_vote_token_has_restricted_nonce() {
     let invalid_sft_nonce = 2;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -302,7 +324,8 @@ fn it_fails_when_vote_token_has_restricted_nonce() {
                     managed_buffer!(b"content signature"),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -1,3 +1,4 @@
+use entity::permission::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use entity::config::*;
@@ -13,6 +14,8 @@ fn it_creates_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
 
@@ -25,7 +28,8 @@ fn it_creates_a_proposal() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -34,20 +38,21 @@ fn it_creates_a_proposal() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
             // proposal
             assert_eq!(1, proposal.id);
-            assert_eq!(managed_address!(&owner_address), proposal.proposer);
+            assert_eq!(sc.users().get_user_id(&managed_address!(&owner_address)), proposal.proposer);
             assert_eq!(managed_buffer!(b"content hash"), proposal.content_hash);
             assert_eq!(ManagedBuffer::new(), proposal.actions_hash);
-            assert_eq!(false, proposal.executed);
-            assert_eq!(managed_biguint!(1), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            assert!(!proposal.executed);
+            assert_eq!(managed_biguint!(1), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
 
             // storage
             assert_eq!(2, sc.next_proposal_id().get());
             assert!(sc.proposal_nft_votes(proposal_id).contains(&1));
-            assert!(sc.withdrawable_votes(proposal.id, &managed_address!(&owner_address)).is_empty());
+            assert!(sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&owner_address))).is_empty());
         })
         .assert_ok();
 }
@@ -58,6 +63,8 @@ fn it_creates_a_proposal_with_poll() {
     let owner_address = setup.owner_address.clone();
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
 
@@ -72,7 +79,8 @@ fn it_creates_a_proposal_with_poll() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 poll_option_id,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
 
             assert_eq!(managed_biguint!(1), sc.proposal_poll(proposal_id, poll_option_id).get());
@@ -87,6 +95,8 @@ fn it_sends_the_nfts_back() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
 
@@ -99,7 +109,8 @@ fn it_sends_the_nfts_back() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -24,27 +24,27 @@ fn it_sets_the_longest_policy_voting_period_for_the_proposal() {
 
             sc.create_permission(
                 managed_buffer!(b"testperm1"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm2"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_permission(
                 managed_buffer!(b"testperm3"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
 
             sc.create_policy(
@@ -87,7 +87,6 @@ fn it_sets_the_longest_policy_voting_period_for_the_proposal() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2"), managed_buffer!(b"testperm3")]);
 
             sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -95,7 +94,8 @@ fn it_sets_the_longest_policy_voting_period_for_the_proposal() {
                 managed_buffer!(b"content signature"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"testrole"]),
             );
         })
         .assert_ok();
@@ -118,6 +118,7 @@ fn it_allows_anyone_to_propose_when_leaderless() {
     let proposer_address = setup.user_address.clone();
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_leaderless();
 
     setup
@@ -134,7 +135,6 @@ fn it_allows_anyone_to_propose_when_leaderless() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm1"), managed_buffer!(b"testperm2"), managed_buffer!(b"testperm3")]);
 
             sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -142,7 +142,8 @@ fn it_allows_anyone_to_propose_when_leaderless() {
                 managed_buffer!(b"content signature"),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
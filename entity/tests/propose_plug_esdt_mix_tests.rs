@@ -1,3 +1,4 @@
+use entity::permission::*;
 use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::types::*;
@@ -14,7 +15,9 @@ fn it_combines_propose_weights_from_plug_and_esdts() {
     let plug_weight = 100;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_plug(100, 50);
+    setup.configure_gov_token_decimals(0);
 
     // propose
     setup
@@ -26,7 +29,8 @@ fn it_combines_propose_weights_from_plug_and_esdts() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -36,29 +40,30 @@ fn it_combines_propose_weights_from_plug_and_esdts() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
             // check proposal
             assert_eq!(1, proposal.id);
-            assert_eq!(managed_address!(&proposer_address), proposal.proposer);
+            assert_eq!(sc.users().get_user_id(&managed_address!(&proposer_address)), proposal.proposer);
             assert_eq!(ManagedBuffer::new(), proposal.actions_hash);
-            assert_eq!(false, proposal.executed);
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + plug_weight), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            assert!(!proposal.executed);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + plug_weight), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
             assert_eq!(2, sc.next_proposal_id().get());
 
             // check withdrawable
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&proposer_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&proposer_address))).contains(&proposal.id));
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&proposer_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&proposer_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.payment.amount);
 
             // check guarded tokens
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&proposer_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&proposer_address))).contains(&proposal.id));
         })
         .assert_ok();
 }
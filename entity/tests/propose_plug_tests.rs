@@ -1,7 +1,6 @@
 use entity::config::*;
 use entity::governance::*;
 use entity::permission::*;
-use entity::plug::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -15,14 +14,7 @@ fn it_creates_a_proposal_using_the_plugs_weight() {
     let proposal_id = 1;
 
     setup.configure_plug(100, 50);
-
-    setup
-        .blockchain
-        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
-            sc.create_role(managed_buffer!(b"builder"));
-            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
-        })
-        .assert_ok();
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -33,7 +25,8 @@ fn it_creates_a_proposal_using_the_plugs_weight() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -42,14 +35,14 @@ fn it_creates_a_proposal_using_the_plugs_weight() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
-            assert_eq!(managed_biguint!(100), proposal.votes_for);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
 
             let user_id = sc.users().get_user_id(&managed_address!(&proposer_address));
-            assert!(sc.plug_votes(proposal_id).contains(&user_id));
+            assert!(sc.plug_votes(proposal_id, &sc.plug_contract().get()).contains(&user_id));
 
             // not withdrawable
-            assert!(!sc.withdrawable_proposal_ids(&managed_address!(&proposer_address)).contains(&proposal.id));
+            assert!(!sc.locked_votes_proposal_ids(user_id).contains(&proposal_id));
         })
         .assert_ok();
 }
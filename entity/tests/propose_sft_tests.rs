@@ -1,3 +1,4 @@
+use entity::permission::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use entity::config::*;
@@ -14,6 +15,7 @@ fn it_creates_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -28,7 +30,8 @@ fn it_creates_a_proposal() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -37,15 +40,16 @@ fn it_creates_a_proposal() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
             // proposal
             assert_eq!(1, proposal.id);
-            assert_eq!(managed_address!(&owner_address), proposal.proposer);
+            assert_eq!(sc.users().get_user_id(&managed_address!(&owner_address)), proposal.proposer);
             assert_eq!(managed_buffer!(b"content hash"), proposal.content_hash);
             assert_eq!(managed_buffer!(b""), proposal.actions_hash);
-            assert_eq!(false, proposal.executed);
-            assert_eq!(managed_biguint!(3), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            assert!(!proposal.executed);
+            assert_eq!(managed_biguint!(3), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
 
             // storage
             assert_eq!(2, sc.next_proposal_id().get());
@@ -54,9 +58,9 @@ fn it_creates_a_proposal() {
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), vote_sft_nonce).get()
             );
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&owner_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(3), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&owner_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(3), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 }
@@ -67,6 +71,7 @@ fn it_creates_a_proposal_with_poll() {
     let owner_address = setup.owner_address.clone();
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(5), &0);
 
@@ -81,7 +86,8 @@ fn it_creates_a_proposal_with_poll() {
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 poll_option_id,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
 
             assert_eq!(managed_biguint!(2), sc.proposal_poll(proposal_id, poll_option_id).get());
@@ -24,11 +24,11 @@ fn it_signs_a_proposal_on_proposing_when_proposal_requires_signing() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(&sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(&sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -54,7 +54,6 @@ fn it_signs_a_proposal_on_proposing_when_proposal_requires_signing() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -62,7 +61,8 @@ fn it_signs_a_proposal_on_proposing_when_proposal_requires_signing() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -84,7 +84,7 @@ fn it_creates_a_proposal_without_esdt_payment_when_not_required() {
     let proposer_address = setup.user_address;
     let mut proposal_id: u64 = 0;
 
-    // fails if no roles set, therefore requires token weight
+    // fails if the proposer does not have the role yet
     setup
         .blockchain
         .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
@@ -94,10 +94,11 @@ fn it_creates_a_proposal_without_esdt_payment_when_not_required() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[b"builder"]),
             );
         })
-        .assert_user_error("insufficient vote weight");
+        .assert_user_error("action not allowed for user");
 
     setup
         .blockchain
@@ -106,11 +107,11 @@ fn it_creates_a_proposal_without_esdt_payment_when_not_required() {
             sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
             sc.create_permission(
                 managed_buffer!(b"testperm"),
-                managed_biguint!(0),
-                managed_address!(sc_address),
-                managed_buffer!(b"testendpoint"),
-                ManagedVec::new(),
-                ManagedVec::new(),
+                Some(managed_biguint!(0)),
+                Some(managed_address!(sc_address)),
+                Some(managed_buffer!(b"testendpoint")),
+                None,
+                None,
             );
             sc.create_policy(
                 managed_buffer!(b"builder"),
@@ -136,7 +137,6 @@ fn it_creates_a_proposal_without_esdt_payment_when_not_required() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -144,7 +144,8 @@ fn it_creates_a_proposal_without_esdt_payment_when_not_required() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
@@ -166,6 +167,7 @@ fn it_does_not_self_sign_a_proposal_when_proposer_does_not_have_any_roles() {
     let mut proposal_id: u64 = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -183,7 +185,8 @@ fn it_does_not_self_sign_a_proposal_when_proposer_does_not_have_any_roles() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -1,5 +1,6 @@
 use entity::config::*;
 use entity::governance::*;
+use entity::permission::ROLE_BUILTIN_LEADER;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -23,7 +24,8 @@ fn it_fails_to_verify_trusted_host_when_no_signature_given() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_LEADER]),
             );
         })
         .assert_error(10, "invalid signature");
@@ -1,9 +1,11 @@
+#![allow(dead_code)]
+
 multiversx_sc::imports!();
 
 use entity::config::*;
-use entity::governance::*;
-use entity::permission::PermissionModule;
-use entity::permission::ROLE_BUILTIN_LEADER;
+use entity::governance::proposal::{Action, Proposal, ProposalDetails, ProposalModule};
+use entity::governance::token::TokenModule;
+use entity::permission::{PermissionModule, QuorumMode, PERMISSION_NOOP, PERMISSION_WILDCARD, ROLE_BUILTIN_LEADER, ROLE_BUILTIN_MEMBER};
 use entity::plug::*;
 use entity::*;
 use multiversx_sc_scenario::testing_framework::BlockchainStateWrapper;
@@ -16,10 +18,9 @@ pub const ENTITY_FAKE_TOKEN_ID: &[u8] = b"FAKE-abcdef";
 pub const MIN_PROPOSE_WEIGHT: u64 = 2;
 pub const POLL_DEFAULT_ID: u8 = 0;
 pub const QURUM: u64 = 50;
-pub const WASM_PATH: &'static str = "output/entity.wasm";
-pub const PLUG_EXAMPLE_WASM_PATH: &'static str = "tests/external/plug-example.wasm";
+pub const WASM_PATH: &str = "output/entity.wasm";
+pub const PLUG_EXAMPLE_WASM_PATH: &str = "tests/external/plug-example.wasm";
 
-#[allow(dead_code)]
 pub struct EntitySetup<ObjBuilder>
 where
     ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
@@ -68,10 +69,11 @@ where
     pub fn configure_gov_token(&mut self, lock_vote_tokens: bool) {
         self.blockchain
             .execute_tx(&self.owner_address, &self.contract, &rust_biguint!(0), |sc| {
-                sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(MIN_PROPOSE_WEIGHT), lock_vote_tokens);
+                sc.configure_governance_token(managed_token_id!(ENTITY_GOV_TOKEN_ID), managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY), lock_vote_tokens, None);
 
                 // override defaults
-                sc.quorum().set(managed_biguint!(QURUM));
+                sc.default_quorum().set(managed_biguint!(QURUM));
+                sc.default_quorum_mode().set(QuorumMode::Absolute);
                 sc.min_propose_weight().set(managed_biguint!(MIN_PROPOSE_WEIGHT));
 
                 // assert
@@ -80,6 +82,49 @@ where
             .assert_ok();
     }
 
+    /// Gives the member role a token-weighted policy with the current default quorum
+    /// and assigns it to the owner and the user, so that they can propose and vote.
+    pub fn configure_member_voting(&mut self) {
+        let owner_address = self.owner_address.clone();
+        let user_address = self.user_address.clone();
+
+        self.blockchain
+            .execute_tx(self.contract.address_ref(), &self.contract, &rust_biguint!(0), |sc| {
+                let role = managed_buffer!(ROLE_BUILTIN_MEMBER);
+                let quorum = sc.default_quorum().get();
+
+                sc.configure_member_role();
+                sc.update_policy(&role, managed_buffer!(PERMISSION_NOOP), |policy| policy.quorum = quorum);
+                sc.assign_role(managed_address!(&owner_address), role.clone());
+                sc.assign_role(managed_address!(&user_address), role);
+            })
+            .assert_ok();
+    }
+
+    pub fn assign_member(&mut self, address: &Address) {
+        self.blockchain
+            .execute_tx(self.contract.address_ref(), &self.contract, &rust_biguint!(0), |sc| {
+                sc.assign_role(managed_address!(address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+            })
+            .assert_ok();
+    }
+
+    pub fn configure_gov_token_decimals(&mut self, decimals: u8) {
+        self.blockchain
+            .execute_tx(self.contract.address_ref(), &self.contract, &rust_biguint!(0), |sc| {
+                sc.change_gov_token_decimals_endpoint(decimals);
+            })
+            .assert_ok();
+    }
+
+    pub fn configure_min_propose_weight(&mut self, weight: u64) {
+        self.blockchain
+            .execute_tx(&self.owner_address, &self.contract, &rust_biguint!(0), |sc| {
+                sc.min_propose_weight().set(managed_biguint!(weight));
+            })
+            .assert_ok();
+    }
+
     pub fn configure_leaderless(&mut self) {
         let owner_address = self.owner_address.clone();
 
@@ -100,18 +145,127 @@ where
             .assert_ok();
     }
 
-    pub fn configure_plug(&mut self, quorum: u64, min_propose_weight: u64) {
-        let plug_contract = self
-            .blockchain
-            .create_sc_account(&rust_biguint!(0), Some(&self.owner_address), fakes::contract_obj, PLUG_EXAMPLE_WASM_PATH);
+    /// Creates the example plug in the same shard as the entity, as required to look up plug-bound role members.
+    pub fn create_plug_contract(&mut self) -> Address {
+        let mut address = self.contract.address_ref().to_vec();
+        address[16] ^= 0xff;
+
+        self.blockchain
+            .create_sc_account_fixed_address(
+                &Address::from_slice(&address),
+                &rust_biguint!(0),
+                Some(&self.owner_address),
+                fakes::contract_obj,
+                PLUG_EXAMPLE_WASM_PATH,
+            )
+            .address_ref()
+            .clone()
+    }
+
+    /// Returns the code under which the test vm registers a contract created from the given wasm path,
+    /// so that it can be deployed from within the entity.
+    pub fn registered_contract_code(&self, wasm_path: &str) -> Vec<u8> {
+        let mut path = std::env::current_dir().unwrap();
+        path.pop();
+        path.push(wasm_path);
+
+        format!("MISSING:{:?}", path).into_bytes()
+    }
+
+    pub fn configure_plug(&mut self, quorum: u64, min_propose_weight: u64) -> Address {
+        let plug_contract = self.create_plug_contract();
 
         self.blockchain
             .execute_tx(&self.owner_address, &self.contract, &rust_biguint!(0), |sc| {
-                sc.plug_contract().set(managed_address!(&plug_contract.address_ref()));
-                sc.try_change_quorum(managed_biguint!(quorum));
+                sc.plug_contract().set(managed_address!(&plug_contract));
+                sc.plugs().insert(
+                    managed_address!(&plug_contract),
+                    PlugSettings {
+                        multiplier: PLUG_MULTIPLIER_BASE,
+                        weight_decimals: 0,
+                        failure_policy: PlugFailurePolicy::Abort,
+                    },
+                );
+                sc.try_change_default_quorum(managed_biguint!(quorum));
                 sc.try_change_min_propose_weight(managed_biguint!(min_propose_weight));
             })
             .assert_ok();
+
+        plug_contract
+    }
+}
+
+pub fn roles(names: &[&[u8]]) -> MultiValueEncoded<DebugApi, ManagedBuffer<DebugApi>> {
+    names.iter().map(|name| managed_buffer!(*name)).collect()
+}
+
+pub fn create_signed_leader_proposal(sc: &entity::ContractObj<DebugApi>) -> Proposal<DebugApi> {
+    let leader_role = managed_buffer!(ROLE_BUILTIN_LEADER);
+    let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(vec![dummy_action()]));
+
+    let proposal = Proposal {
+        id: 1,
+        proposer: 1,
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash,
+        starts_at: 0,
+        ends_at: 1_000 + 60,
+        executed: false,
+        roles: ManagedVec::from_single_item(leader_role.clone()),
+    };
+
+    sc.proposals(proposal.id).set(&proposal);
+    sc.proposal_details(proposal.id).set(ProposalDetails {
+        token: None,
+        plug: None,
+        quorum: managed_biguint!(0),
+        permissions: ManagedVec::from_single_item(managed_buffer!(PERMISSION_WILDCARD)),
+    });
+
+    sc.proposal_signers(proposal.id, &leader_role).insert(proposal.proposer);
+    sc.record_proposal_success(&proposal);
+
+    proposal
+}
+
+pub fn create_active_weighted_proposal(sc: &entity::ContractObj<DebugApi>, voter_address: &Address, starts_at: u64) -> Proposal<DebugApi> {
+    let member_role = managed_buffer!(ROLE_BUILTIN_MEMBER);
+    let permission = managed_buffer!(b"testperm");
+
+    sc.assign_role(managed_address!(voter_address), member_role.clone());
+    sc.create_permission(permission.clone(), None, None, None, None, None);
+    sc.create_policy_weighted_endpoint(member_role.clone(), permission.clone(), None, None, None);
+
+    let proposal = Proposal {
+        id: 1,
+        proposer: sc.users().get_user_id(&managed_address!(voter_address)),
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash: ManagedBuffer::new(),
+        starts_at,
+        ends_at: starts_at + 60,
+        executed: false,
+        roles: ManagedVec::from_single_item(member_role),
+    };
+
+    sc.proposals(proposal.id).set(&proposal);
+    sc.proposal_details(proposal.id).set(ProposalDetails {
+        token: None,
+        plug: None,
+        quorum: managed_biguint!(0),
+        permissions: ManagedVec::from_single_item(permission),
+    });
+
+    proposal
+}
+
+pub fn dummy_action() -> Action<DebugApi> {
+    Action::<DebugApi> {
+        destination: managed_address!(&Address::zero()),
+        endpoint: managed_buffer!(b"myendpoint"),
+        arguments: ManagedVec::new(),
+        gas_limit: 5_000_000u64,
+        value: managed_biguint!(0),
+        payments: ManagedVec::new(),
     }
 }
 
@@ -19,8 +19,10 @@ fn it_signs_a_proposal() {
 
     setup
         .blockchain
-        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
             sc.create_role(managed_buffer!(b"builder"));
+            sc.create_permission(managed_buffer!(b"perm"), None, None, None, None, None);
+            sc.create_policy_majority_endpoint(managed_buffer!(b"builder"), managed_buffer!(b"perm"));
 
             sc.assign_role(managed_address!(&owner_address), managed_buffer!(b"builder"));
             sc.assign_role(managed_address!(&signer_address), managed_buffer!(b"builder"));
@@ -29,7 +31,7 @@ fn it_signs_a_proposal() {
 
     setup
         .blockchain
-        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(owner_address, &setup.contract, &rust_biguint!(0), |sc| {
             let mut actions = Vec::<Action<DebugApi>>::new();
             actions.push(Action::<DebugApi> {
                 destination: managed_address!(&action_receiver),
@@ -41,7 +43,6 @@ fn it_signs_a_proposal() {
             });
 
             let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
-            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
 
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
@@ -49,14 +50,15 @@ fn it_signs_a_proposal() {
                 ManagedBuffer::new(),
                 actions_hash,
                 POLL_DEFAULT_ID,
-                actions_permissions,
+                0,
+                roles(&[b"builder"]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_tx(&signer_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(signer_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.sign_endpoint(proposal_id, OptionalValue::None);
 
             assert_eq!(2, sc.proposal_signers(proposal_id, &managed_buffer!(b"builder")).len());
@@ -0,0 +1,203 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::staking::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_stakes_governance_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&staker_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.stake_endpoint();
+
+            assert_eq!(managed_biguint!(100), sc.get_stake_view(managed_address!(&staker_address)));
+            assert_eq!(managed_biguint!(100), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_stake_another_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&staker_address, &setup.contract, ENTITY_FAKE_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_votes_with_the_stake_without_payment() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &staker_address, 10);
+            let staker = sc.users().get_user_id(&managed_address!(&staker_address));
+
+            sc.vote(staker, proposal, VoteType::For, managed_biguint!(0), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
+            assert_eq!(70, sc.stake_unlocks_at(staker).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_the_stake_only_once_per_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &staker_address, 10);
+            let staker = sc.users().get_user_id(&managed_address!(&staker_address));
+
+            sc.vote(staker, proposal, VoteType::For, managed_biguint!(0), 0);
+            sc.vote(staker, sc.proposals(1).get(), VoteType::For, managed_biguint!(5), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(105), role_info.votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_unstakes_and_claims_after_the_unbonding_period() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(40));
+
+            let (unbonding, unbonds_at) = sc.get_unbonding_stake_view(managed_address!(&staker_address)).into_tuple();
+            assert_eq!(managed_biguint!(60), sc.get_stake_view(managed_address!(&staker_address)));
+            assert_eq!(managed_biguint!(40), unbonding);
+            assert_eq!(UNBONDING_PERIOD_MINUTES_DEFAULT as u64 * 60, unbonds_at);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.claim_unstaked_endpoint();
+        })
+        .assert_user_error("stake is unbonding");
+
+    setup.blockchain.set_block_timestamp(UNBONDING_PERIOD_MINUTES_DEFAULT as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.claim_unstaked_endpoint();
+
+            assert_eq!(managed_biguint!(60), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&staker_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - 60));
+}
+
+#[test]
+fn it_fails_to_unstake_more_than_staked() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(101));
+        })
+        .assert_user_error("insufficient stake");
+}
+
+#[test]
+fn it_fails_to_unstake_while_the_stake_counts_toward_an_active_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &staker_address, 10);
+            let staker = sc.users().get_user_id(&managed_address!(&staker_address));
+
+            sc.vote(staker, proposal, VoteType::For, managed_biguint!(0), 0);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(100));
+        })
+        .assert_user_error("stake is locked");
+}
+
+#[test]
+fn it_fails_to_change_the_unbonding_period_when_exceeding_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_unbonding_period_in_minutes_endpoint(UNBONDING_PERIOD_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max unbonding period exceeded");
+}
+
+fn stake<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, staker_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_esdt_transfer(staker_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(amount), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_ok();
+}
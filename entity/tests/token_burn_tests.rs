@@ -1,4 +1,5 @@
-use entity::governance::*;
+use entity::config::*;
+use entity::governance::token::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -38,3 +39,33 @@ fn it_fails_when_caller_not_self() {
         })
         .assert_user_error("action not allowed by user");
 }
+
+#[test]
+fn it_tracks_the_supply_of_a_burned_additional_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .set_esdt_local_roles(setup.contract.address_ref(), ENTITY_FAKE_TOKEN_ID, &[EsdtLocalRole::Burn]);
+
+    setup
+        .blockchain
+        .set_esdt_balance(setup.contract.address_ref(), ENTITY_FAKE_TOKEN_ID, &rust_biguint!(1_000));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.register_gov_token_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 20_000, true, managed_biguint!(5_000));
+            sc.burn_endpoint(managed_token_id!(ENTITY_FAKE_TOKEN_ID), 0, managed_biguint!(1_000));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(4_000), sc.additional_gov_token_supply(&managed_token_id!(ENTITY_FAKE_TOKEN_ID)).get());
+        })
+        .assert_ok();
+}
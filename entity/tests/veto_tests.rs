@@ -0,0 +1,119 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_vetoes_a_succeeded_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            sc.veto_endpoint(proposal.id, managed_buffer!(b"reason hash"));
+
+            assert_eq!(ProposalStatus::Vetoed, sc.get_proposal_status(&proposal));
+            assert_eq!(managed_buffer!(b"reason hash"), sc.proposal_veto_reason(proposal.id).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_vetoes_a_queued_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+            sc.default_execution_delay_minutes().set(60);
+
+            let proposal = create_signed_leader_proposal(&sc);
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status(&proposal));
+
+            sc.veto_endpoint(proposal.id, managed_buffer!(b"reason hash"));
+
+            assert_eq!(ProposalStatus::Vetoed, sc.get_proposal_status(&proposal));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_veto_when_caller_not_guardian() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_signed_leader_proposal(&sc);
+
+            sc.veto_endpoint(proposal.id, managed_buffer!(b"reason hash"));
+        })
+        .assert_user_error("caller must be guardian");
+}
+
+#[test]
+fn it_fails_to_veto_an_executed_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+
+            let mut proposal = create_signed_leader_proposal(&sc);
+            proposal.executed = true;
+            sc.proposals(proposal.id).set(&proposal);
+
+            sc.veto_endpoint(proposal.id, managed_buffer!(b"reason hash"));
+        })
+        .assert_user_error("proposal can not be vetoed");
+}
+
+#[test]
+fn it_fails_to_veto_without_reason_hash() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            sc.veto_endpoint(proposal.id, ManagedBuffer::new());
+        })
+        .assert_user_error("reason hash must not be empty");
+}
+
+#[test]
+fn it_fails_to_execute_a_vetoed_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&guardian_address), managed_buffer!(ROLE_BUILTIN_GUARDIAN));
+
+            let proposal = create_signed_leader_proposal(&sc);
+
+            sc.veto_endpoint(proposal.id, managed_buffer!(b"reason hash"));
+            sc.execute_endpoint(proposal.id, MultiValueManagedVec::from(vec![dummy_action()]));
+        })
+        .assert_user_error("proposal has been vetoed");
+}
@@ -0,0 +1,121 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_tallies_abstain_votes_separately() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let proposal = create_proposal_for_role(&role);
+
+            sc.vote_for_role(&proposal, &role, VoteType::For, &managed_biguint!(10));
+            sc.vote_for_role(&proposal, &role, VoteType::Abstain, &managed_biguint!(25));
+            sc.vote_for_role(&proposal, &role, VoteType::Abstain, &managed_biguint!(5));
+
+            let role_info = sc.proposal_role_info(proposal.id, &role).get();
+            assert_eq!(managed_biguint!(10), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
+            assert_eq!(managed_biguint!(30), role_info.votes_abstain);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_counts_abstain_votes_toward_the_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let proposal = create_proposal_for_role(&role);
+            let policy = create_weighted_policy(100);
+
+            sc.proposal_role_info(proposal.id, &role).set(ProposalRole {
+                votes_for: managed_biguint!(60),
+                votes_against: managed_biguint!(0),
+                votes_abstain: managed_biguint!(0),
+            });
+
+            assert!(!sc.has_sufficient_votes(&proposal, &role, &policy));
+
+            sc.vote_for_role(&proposal, &role, VoteType::Abstain, &managed_biguint!(40));
+
+            assert!(sc.has_sufficient_votes(&proposal, &role, &policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_count_abstain_votes_toward_the_ratio() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let proposal = create_proposal_for_role(&role);
+            let policy = create_weighted_policy(1);
+
+            sc.proposal_role_info(proposal.id, &role).set(ProposalRole {
+                votes_for: managed_biguint!(10),
+                votes_against: managed_biguint!(20),
+                votes_abstain: managed_biguint!(1_000),
+            });
+
+            assert!(!sc.has_sufficient_votes(&proposal, &role, &policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_pass_with_abstain_votes_only() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let proposal = create_proposal_for_role(&role);
+            let policy = create_weighted_policy(1);
+
+            sc.vote_for_role(&proposal, &role, VoteType::Abstain, &managed_biguint!(100));
+
+            assert!(!sc.has_sufficient_votes(&proposal, &role, &policy));
+        })
+        .assert_ok();
+}
+
+fn create_proposal_for_role(role: &ManagedBuffer<DebugApi>) -> Proposal<DebugApi> {
+    Proposal {
+        id: 1,
+        proposer: 1,
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash: ManagedBuffer::new(),
+        starts_at: 0,
+        ends_at: 60,
+        executed: false,
+        roles: ManagedVec::from_single_item(role.clone()),
+    }
+}
+
+fn create_weighted_policy(quorum: u64) -> Policy<DebugApi> {
+    Policy {
+        method: PolicyMethod::Weight,
+        quorum: managed_biguint!(quorum),
+        quorum_mode: QuorumMode::Absolute,
+        voting_period_minutes: 60,
+        execution_delay_minutes: 0,
+        execution_window_minutes: 0,
+        approval_threshold: APPROVAL_THRESHOLD_DEFAULT,
+    }
+}
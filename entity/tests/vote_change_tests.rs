@@ -0,0 +1,267 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc::imports::OptionalValue;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_records_a_vote_receipt() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 2);
+
+            let receipt = sc.vote_receipts(1, voter).get();
+            assert_eq!(VoteType::For, receipt.vote_type);
+            assert_eq!(managed_biguint!(25), receipt.weight);
+            assert_eq!(2, receipt.option_id);
+            assert_eq!(10, receipt.voted_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_adds_up_repeated_votes_in_the_receipt() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+            sc.vote(voter, sc.proposals(1).get(), VoteType::For, managed_biguint!(15), 0);
+
+            assert_eq!(managed_biguint!(40), sc.vote_receipts(1, voter).get().weight);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_vote_again_with_another_vote_type() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+            sc.vote(voter, sc.proposals(1).get(), VoteType::Against, managed_biguint!(25), 0);
+        })
+        .assert_user_error("already voted with another vote type");
+}
+
+#[test]
+fn it_changes_a_vote_from_for_to_against() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 1);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(1, VoteType::Against, OptionalValue::Some(2));
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(0), role_info.votes_for);
+            assert_eq!(managed_biguint!(25), role_info.votes_against);
+
+            assert_eq!(managed_biguint!(0), sc.proposal_poll(1, 1).get());
+            assert_eq!(managed_biguint!(25), sc.proposal_poll(1, 2).get());
+
+            let receipt = sc.get_vote_view(1, managed_address!(&voter_address)).into_option().unwrap();
+            assert_eq!(VoteType::Against, receipt.vote_type);
+            assert_eq!(2, receipt.option_id);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_a_vote_without_previous_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(1, VoteType::Against, OptionalValue::None);
+        })
+        .assert_user_error("no vote to change");
+}
+
+#[test]
+fn it_fails_to_change_a_vote_when_unchanged() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(1, VoteType::For, OptionalValue::None);
+        })
+        .assert_user_error("vote is unchanged");
+}
+
+#[test]
+fn it_fails_to_change_a_vote_when_proposal_not_active() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(1, VoteType::Against, OptionalValue::None);
+        })
+        .assert_user_error("proposal not active");
+}
+
+#[test]
+fn it_returns_nothing_for_unknown_voters() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert!(sc.get_vote_view(1, managed_address!(&voter_address)).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_changes_a_vote_on_the_roles_credited_when_voting() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &proposer_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_or_create_user(&managed_address!(&voter_address));
+            sc.assign_role(managed_address!(&voter_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+
+            let receipt = sc.vote_receipts(1, voter).get();
+            assert_eq!(1, receipt.roles.len());
+            assert_eq!(managed_buffer!(ROLE_BUILTIN_MEMBER), receipt.roles.get(0).role);
+
+            sc.unassign_role(managed_address!(&voter_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(1, VoteType::Against, OptionalValue::None);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(0), role_info.votes_for);
+            assert_eq!(managed_biguint!(25), role_info.votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_clears_the_vote_receipts_when_persisting_proposal_results() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &voter_address, sc.blockchain().get_block_timestamp());
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(25), 0);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(1_000);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.persist_proposal_results(1);
+
+            assert!(sc.vote_receipts(1, voter).is_empty());
+            assert!(sc.proposal_voters(1).is_empty());
+        })
+        .assert_ok();
+}
@@ -1,3 +1,4 @@
+use entity::permission::*;
 use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::codec::multi_types::*;
@@ -14,6 +15,7 @@ fn it_votes_for_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -30,7 +32,8 @@ fn it_votes_for_a_proposal() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -44,17 +47,19 @@ fn it_votes_for_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 25), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 25), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT + 25),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal.id));
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(25), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(25), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 
@@ -66,16 +71,18 @@ fn it_votes_for_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 50), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 50), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT + 50),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(2);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(25), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(2);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(25), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 }
@@ -88,6 +95,7 @@ fn it_votes_for_a_proposal_with_poll() {
     let poll_option_id = 2u8;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -104,7 +112,8 @@ fn it_votes_for_a_proposal_with_poll() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     poll_option_id,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -127,6 +136,7 @@ fn it_votes_against_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -143,7 +153,8 @@ fn it_votes_against_a_proposal() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -156,17 +167,19 @@ fn it_votes_against_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), proposal.votes_for);
-            assert_eq!(managed_biguint!(25), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), role_info.votes_for);
+            assert_eq!(managed_biguint!(25), role_info.votes_against);
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT + 25),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal.id));
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(25), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(25), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 
@@ -178,16 +191,18 @@ fn it_votes_against_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), proposal.votes_for);
-            assert_eq!(managed_biguint!(50), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), role_info.votes_for);
+            assert_eq!(managed_biguint!(50), role_info.votes_against);
             assert_eq!(
                 managed_biguint!(MIN_PROPOSE_WEIGHT + 50),
                 sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
             );
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(2);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(25), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(2);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(25), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 }
@@ -198,6 +213,7 @@ fn it_fails_when_proposal_voting_period_has_ended() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -214,7 +230,8 @@ fn it_fails_when_proposal_voting_period_has_ended() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -227,7 +244,7 @@ fn it_fails_when_proposal_voting_period_has_ended() {
         .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
-        .assert_user_error(PROPOSAL_NOT_ACTIVE);
+        .assert_user_error("proposal not active");
 }
 
 #[test]
@@ -236,6 +253,7 @@ fn it_fails_when_less_than_configured_min_vote_weight() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -252,7 +270,8 @@ fn it_fails_when_less_than_configured_min_vote_weight() {
                     ManagedBuffer::new(),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
 
                 sc.try_change_min_vote_weight(managed_biguint!(50));
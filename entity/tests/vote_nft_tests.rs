@@ -1,5 +1,6 @@
+use entity::permission::*;
 use entity::config::*;
-use entity::governance::errors::*;
+use entity::errors::*;
 use entity::governance::*;
 use multiversx_sc::codec::multi_types::*;
 use multiversx_sc::types::*;
@@ -15,6 +16,8 @@ fn it_votes_for_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup
         .blockchain
@@ -31,7 +34,8 @@ fn it_votes_for_a_proposal() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -43,11 +47,13 @@ fn it_votes_for_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(2), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(2), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
             assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
-            assert_eq!(0, sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).len());
-            assert!(sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).is_empty());
+            assert_eq!(0, sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).len());
+            assert!(sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).is_empty());
         })
         .assert_ok();
 }
@@ -60,6 +66,8 @@ fn it_votes_for_a_proposal_with_poll() {
     let poll_option_id = 2u8;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup
         .blockchain
@@ -76,7 +84,8 @@ fn it_votes_for_a_proposal_with_poll() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 poll_option_id,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -98,6 +107,8 @@ fn it_votes_against_a_proposal() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup
         .blockchain
@@ -113,7 +124,8 @@ fn it_votes_against_a_proposal() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -125,11 +137,13 @@ fn it_votes_against_a_proposal() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(1), proposal.votes_for);
-            assert_eq!(managed_biguint!(1), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(1), role_info.votes_for);
+            assert_eq!(managed_biguint!(1), role_info.votes_against);
             assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
-            assert_eq!(0, sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).len());
-            assert!(sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).is_empty());
+            assert_eq!(0, sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).len());
+            assert!(sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).is_empty());
         })
         .assert_ok();
 }
@@ -142,6 +156,8 @@ fn it_sends_the_nfts_back() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0u32);
     setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0u32);
@@ -155,7 +171,8 @@ fn it_sends_the_nfts_back() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -167,11 +184,13 @@ fn it_sends_the_nfts_back() {
 
             let proposal = sc.proposals(proposal_id).get();
 
-            assert_eq!(managed_biguint!(2), proposal.votes_for);
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+
+            assert_eq!(managed_biguint!(2), role_info.votes_for);
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
             assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
-            assert_eq!(0, sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).len());
-            assert!(sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).is_empty());
+            assert_eq!(0, sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).len());
+            assert!(sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).is_empty());
         })
         .assert_ok();
 
@@ -188,6 +207,8 @@ fn it_fails_to_vote_twice_with_the_same_nft() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0u32);
     setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0u32);
@@ -201,7 +222,8 @@ fn it_fails_to_vote_twice_with_the_same_nft() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -218,7 +240,7 @@ fn it_fails_to_vote_twice_with_the_same_nft() {
         .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), |sc| {
             sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
-        .assert_user_error(&String::from_utf8(ALREADY_VOTED_WITH_TOKEN.to_vec()).unwrap());
+        .assert_user_error(core::str::from_utf8(ALREADY_VOTED_WITH_TOKEN).unwrap());
 }
 
 #[test]
@@ -229,6 +251,8 @@ fn it_fails_when_less_than_configured_min_vote_weight() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(false);
+    setup.configure_member_voting();
+    setup.configure_min_propose_weight(1);
 
     setup.blockchain.set_nft_balance(&owner_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0u32);
     setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0u32);
@@ -242,7 +266,8 @@ fn it_fails_when_less_than_configured_min_vote_weight() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
 
             sc.try_change_min_vote_weight(managed_biguint!(3));
@@ -267,6 +292,7 @@ fn it_fails_when_vote_token_has_restricted_nonce() {
     let mut proposal_id = 0;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -300,7 +326,8 @@ fn it_fails_when_vote_token_has_restricted_nonce() {
                     managed_buffer!(b"content signature"),
                     ManagedBuffer::new(),
                     POLL_DEFAULT_ID,
-                    MultiValueManagedVec::new(),
+                    0,
+                    roles(&[ROLE_BUILTIN_MEMBER]),
                 );
             },
         )
@@ -1,3 +1,4 @@
+use entity::permission::*;
 use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::codec::multi_types::*;
@@ -15,7 +16,9 @@ fn it_combines_vote_weights_from_plug_and_esdts() {
     let plug_weight = 100;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_plug(100, 50);
+    setup.configure_gov_token_decimals(0);
 
     // propose as any user
     setup
@@ -27,7 +30,8 @@ fn it_combines_vote_weights_from_plug_and_esdts() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -45,13 +49,14 @@ fn it_combines_vote_weights_from_plug_and_esdts() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(plug_weight + plug_weight + 50), proposal.votes_for); // 100 from proposer + 100 from voter plug + 50 from voter esdt
+            assert_eq!(managed_biguint!(plug_weight + plug_weight + 50), role_info.votes_for); // 100 from proposer + 100 from voter plug + 50 from voter esdt
 
             // has withdrawable esdts
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal.id));
             assert_eq!(
-                sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(1).amount,
+                sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(1).payment.amount,
                 managed_biguint!(50)
             );
         })
@@ -66,7 +71,9 @@ fn it_only_votes_with_payment_weight_after_the_first_vote() {
     let plug_weight = 100;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
     setup.configure_plug(100, 50);
+    setup.configure_gov_token_decimals(0);
 
     // propose as any user
     setup
@@ -78,7 +85,8 @@ fn it_only_votes_with_payment_weight_after_the_first_vote() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -104,18 +112,19 @@ fn it_only_votes_with_payment_weight_after_the_first_vote() {
         .blockchain
         .execute_query(&setup.contract, |sc| {
             let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
             // 100 from proposer + 100 from voter plug + 50 from voter esdt (1st vote) + 50 from voter esdt (2nd vote)
-            assert_eq!(managed_biguint!(plug_weight + plug_weight + 50 + 50), proposal.votes_for);
+            assert_eq!(managed_biguint!(plug_weight + plug_weight + 50 + 50), role_info.votes_for);
 
             // has withdrawable esdts
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal.id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal.id));
             assert_eq!(
-                sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(1).amount,
+                sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(1).payment.amount,
                 managed_biguint!(50)
             );
             assert_eq!(
-                sc.withdrawable_votes(proposal.id, &managed_address!(&voter_address)).get(2).amount,
+                sc.locked_votes(proposal.id, sc.users().get_user_id(&managed_address!(&voter_address))).get(2).payment.amount,
                 managed_biguint!(50)
             );
         })
@@ -1,6 +1,6 @@
 use entity::config::*;
 use entity::governance::*;
-use entity::plug::*;
+use entity::permission::ROLE_BUILTIN_MEMBER;
 use multiversx_sc::codec::multi_types::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
@@ -15,6 +15,7 @@ fn it_votes_for_a_proposal_using_the_plugs_weight() {
     let proposal_id = 1;
 
     setup.configure_plug(100, 50);
+    setup.configure_member_voting();
 
     // propose as any user
     setup
@@ -26,7 +27,8 @@ fn it_votes_for_a_proposal_using_the_plugs_weight() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -43,16 +45,16 @@ fn it_votes_for_a_proposal_using_the_plugs_weight() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(200), proposal.votes_for); // 100 from proposer + 100 from voter
-            assert_eq!(managed_biguint!(0), proposal.votes_against);
+            assert_eq!(managed_biguint!(200), role_info.votes_for); // 100 from proposer + 100 from voter
+            assert_eq!(managed_biguint!(0), role_info.votes_against);
 
             let user_id = sc.users().get_user_id(&managed_address!(&voter_address));
-            assert!(sc.plug_votes(proposal_id).contains(&user_id));
+            assert!(sc.plug_votes(proposal_id, &sc.plug_contract().get()).contains(&user_id));
 
             // not withdrawable
-            assert!(!sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal.id));
+            assert!(!sc.locked_votes_proposal_ids(user_id).contains(&proposal_id));
         })
         .assert_ok();
 }
@@ -64,6 +66,7 @@ fn it_fails_to_vote_twice() {
     let proposal_id = 1;
 
     setup.configure_plug(100, 50);
+    setup.configure_member_voting();
 
     // propose as any user
     setup
@@ -75,7 +78,8 @@ fn it_fails_to_vote_twice() {
                 ManagedBuffer::new(),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -97,9 +101,9 @@ fn it_fails_to_vote_twice() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            let proposal = sc.proposals(proposal_id).get();
+            let role_info = sc.proposal_role_info(proposal_id, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
 
-            assert_eq!(managed_biguint!(200), proposal.votes_for); // 100 from proposer + 100 from voter (only once)
+            assert_eq!(managed_biguint!(200), role_info.votes_for); // 100 from proposer + 100 from voter (only once)
         })
         .assert_ok();
 }
@@ -0,0 +1,242 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_creates_a_quadratic_policy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let role = managed_buffer!(b"testrole");
+            let permission = managed_buffer!(b"testperm");
+
+            sc.create_role(role.clone());
+            sc.create_permission(permission.clone(), None, None, None, None, None);
+            sc.create_policy_quadratic_endpoint(role.clone(), permission.clone(), Some(managed_biguint!(5)), None, None);
+
+            let policy = sc.policies(&role).get(&permission).unwrap();
+            assert_eq!(PolicyMethod::Quadratic, policy.method);
+            assert_eq!(QuorumMode::Absolute, policy.quorum_mode);
+            assert_eq!(managed_biguint!(5), policy.quorum);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_tallies_the_square_root_of_the_committed_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let other_voter_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Quadratic);
+            sc.assign_role(managed_address!(&other_voter_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+            let other_voter = sc.users().get_user_id(&managed_address!(&other_voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(100), 1);
+            sc.vote(other_voter, sc.proposals(1).get(), VoteType::Against, managed_biguint!(9), 2);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(10), role_info.votes_for);
+            assert_eq!(managed_biguint!(3), role_info.votes_against);
+            assert_eq!(managed_biguint!(10), sc.proposal_poll(1, 1).get());
+            assert_eq!(managed_biguint!(3), sc.proposal_poll(1, 2).get());
+            assert_eq!(managed_biguint!(100), sc.vote_receipts(1, voter).get().weight);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_aggregates_repeated_votes_before_taking_the_square_root() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Quadratic);
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(100), 1);
+            sc.vote(voter, sc.proposals(1).get(), VoteType::For, managed_biguint!(44), 1);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(12), role_info.votes_for);
+            assert_eq!(managed_biguint!(12), sc.proposal_poll(1, 1).get());
+            assert_eq!(managed_biguint!(144), sc.vote_receipts(1, voter).get().weight);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_moves_the_effective_weight_when_changing_a_quadratic_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Quadratic);
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(50), 0);
+            sc.change_vote(voter, sc.proposals(1).get(), VoteType::Against, 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(0), role_info.votes_for);
+            assert_eq!(managed_biguint!(7), role_info.votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_linear_tallies_for_weighted_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Weight);
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(100), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_applies_the_square_root_only_to_roles_with_a_quadratic_policy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Quadratic);
+            let linear_role = managed_buffer!(b"linearrole");
+
+            sc.create_role(linear_role.clone());
+            sc.assign_role(managed_address!(&voter_address), linear_role.clone());
+            sc.create_policy_weighted_endpoint(linear_role.clone(), managed_buffer!(b"testperm"), None, None, None);
+
+            proposal.roles.push(linear_role.clone());
+            sc.proposals(proposal.id).set(&proposal);
+
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(100), 1);
+
+            assert_eq!(managed_biguint!(10), sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get().votes_for);
+            assert_eq!(managed_biguint!(100), sc.proposal_role_info(1, &linear_role).get().votes_for);
+            assert_eq!(managed_biguint!(10), sc.proposal_poll(1, 1).get());
+
+            let receipt = sc.vote_receipts(1, voter).get();
+            assert_eq!(managed_biguint!(100), receipt.weight);
+            assert_eq!(managed_biguint!(10), receipt.roles.get(0).weight);
+            assert_eq!(managed_biguint!(100), receipt.roles.get(1).weight);
+            assert_eq!(managed_biguint!(10), receipt.poll_weight);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_compares_the_quorum_of_a_quadratic_policy_against_the_committed_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let other_voter_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_proposal_with_policy(&sc, &voter_address, PolicyMethod::Quadratic);
+            let member_role = managed_buffer!(ROLE_BUILTIN_MEMBER);
+            sc.assign_role(managed_address!(&other_voter_address), member_role.clone());
+            sc.update_policy(&member_role, managed_buffer!(b"testperm"), |policy| policy.quorum = managed_biguint!(150));
+
+            let voter = sc.users().get_user_id(&managed_address!(&voter_address));
+            let other_voter = sc.users().get_user_id(&managed_address!(&other_voter_address));
+            let policy = sc.policies(&member_role).get(&managed_buffer!(b"testperm")).unwrap();
+
+            sc.vote(voter, proposal, VoteType::For, managed_biguint!(100), 0);
+            assert!(!sc.has_sufficient_votes(&sc.proposals(1).get(), &member_role, &policy));
+
+            sc.vote(other_voter, sc.proposals(1).get(), VoteType::Abstain, managed_biguint!(100), 0);
+            assert_eq!(managed_biguint!(10), sc.proposal_role_info(1, &member_role).get().votes_for);
+            assert_eq!(managed_biguint!(200), sc.proposal_role_quorum_weight(1, &member_role).get());
+            assert!(sc.has_sufficient_votes(&sc.proposals(1).get(), &member_role, &policy));
+
+            sc.change_vote(other_voter, sc.proposals(1).get(), VoteType::Against, 0);
+            assert_eq!(managed_biguint!(100), sc.proposal_role_quorum_weight(1, &member_role).get());
+            assert!(!sc.has_sufficient_votes(&sc.proposals(1).get(), &member_role, &policy));
+        })
+        .assert_ok();
+}
+
+fn create_active_proposal_with_policy(sc: &entity::ContractObj<DebugApi>, voter_address: &Address, method: PolicyMethod) -> Proposal<DebugApi> {
+    let member_role = managed_buffer!(ROLE_BUILTIN_MEMBER);
+    let permission = managed_buffer!(b"testperm");
+
+    sc.assign_role(managed_address!(voter_address), member_role.clone());
+    sc.create_permission(permission.clone(), None, None, None, None, None);
+
+    match method {
+        PolicyMethod::Quadratic => sc.create_policy_quadratic_endpoint(member_role.clone(), permission.clone(), None, None, None),
+        _ => sc.create_policy_weighted_endpoint(member_role.clone(), permission.clone(), None, None, None),
+    }
+
+    let proposal = Proposal {
+        id: 1,
+        proposer: sc.users().get_user_id(&managed_address!(voter_address)),
+        content_hash: managed_buffer!(b"content hash"),
+        actions_hash: ManagedBuffer::new(),
+        starts_at: 10,
+        ends_at: 70,
+        executed: false,
+        roles: ManagedVec::from_single_item(member_role),
+    };
+
+    sc.proposals(proposal.id).set(&proposal);
+    sc.proposal_details(proposal.id).set(ProposalDetails {
+        token: None,
+        plug: None,
+        quorum: managed_biguint!(0),
+        permissions: ManagedVec::from_single_item(permission),
+    });
+
+    proposal
+}
@@ -0,0 +1,153 @@
+use entity::config::*;
+use entity::governance::delegation::*;
+use entity::governance::proposal::*;
+use entity::governance::staking::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_returns_the_stake_right_before_a_timestamp() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_block_timestamp(10);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(20);
+    stake(&mut setup, &staker_address, 50);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let staker = managed_address!(&staker_address);
+
+            assert_eq!(managed_biguint!(0), sc.get_stake_at_view(staker.clone(), 10));
+            assert_eq!(managed_biguint!(100), sc.get_stake_at_view(staker.clone(), 11));
+            assert_eq!(managed_biguint!(100), sc.get_stake_at_view(staker.clone(), 20));
+            assert_eq!(managed_biguint!(150), sc.get_stake_at_view(staker, 21));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_ignores_stake_added_after_the_proposal_started() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+    stake(&mut setup, &staker_address, 500);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = create_active_weighted_proposal(&sc, &staker_address, 10);
+            let staker = sc.users().get_user_id(&managed_address!(&staker_address));
+
+            sc.vote(staker, proposal, VoteType::For, managed_biguint!(0), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_stake_unstaked_after_the_proposal_started() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let staker_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    stake(&mut setup, &staker_address, 100);
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            create_active_weighted_proposal(&sc, &staker_address, 10);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&staker_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.unstake_endpoint(managed_biguint!(100));
+
+            let staker = sc.users().get_user_id(&managed_address!(&staker_address));
+            sc.vote(staker, sc.proposals(1).get(), VoteType::For, managed_biguint!(0), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(100), role_info.votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_evaluates_the_delegation_at_the_proposal_start() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+    let new_delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(10);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            create_active_weighted_proposal(&sc, &delegate_address, 10);
+            sc.assign_role(managed_address!(&new_delegate_address), managed_buffer!(ROLE_BUILTIN_MEMBER));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&new_delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let delegate = sc.users().get_user_id(&managed_address!(&delegate_address));
+            let new_delegate = sc.users().get_user_id(&managed_address!(&new_delegate_address));
+
+            sc.vote(new_delegate, sc.proposals(1).get(), VoteType::Against, managed_biguint!(1), 0);
+            sc.vote(delegate, sc.proposals(1).get(), VoteType::For, managed_biguint!(1), 0);
+
+            let role_info = sc.proposal_role_info(1, &managed_buffer!(ROLE_BUILTIN_MEMBER)).get();
+            assert_eq!(managed_biguint!(101), role_info.votes_for);
+            assert_eq!(managed_biguint!(1), role_info.votes_against);
+        })
+        .assert_ok();
+}
+
+fn stake<ObjBuilder>(setup: &mut EntitySetup<ObjBuilder>, staker_address: &Address, amount: u64)
+where
+    ObjBuilder: 'static + Copy + Fn() -> entity::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain
+        .execute_esdt_transfer(staker_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(amount), |sc| {
+            sc.stake_endpoint();
+        })
+        .assert_ok();
+}
@@ -1,7 +1,7 @@
 use entity::config::*;
 use entity::governance::*;
+use entity::permission::ROLE_BUILTIN_MEMBER;
 use multiversx_sc::codec::multi_types::*;
-use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
 
@@ -16,35 +16,40 @@ fn it_withdraws_tokens_for_all_voters() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
+    setup.assign_member(voter1_address);
+    setup.assign_member(voter2_address);
+    setup.assign_member(voter3_address);
 
-    setup.blockchain.set_esdt_balance(&voter1_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(5));
-    setup.blockchain.set_esdt_balance(&voter2_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(10));
-    setup.blockchain.set_esdt_balance(&voter3_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(20));
+    setup.blockchain.set_esdt_balance(voter1_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(5));
+    setup.blockchain.set_esdt_balance(voter2_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(10));
+    setup.blockchain.set_esdt_balance(voter3_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(20));
 
     setup
         .blockchain
-        .execute_esdt_transfer(&voter1_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
+        .execute_esdt_transfer(voter1_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&voter2_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
+        .execute_esdt_transfer(voter2_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
             sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&voter3_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
+        .execute_esdt_transfer(voter3_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
             sc.vote_against_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
@@ -60,18 +65,18 @@ fn it_withdraws_tokens_for_all_voters() {
             let voter2_id = sc.users().get_user_id(&managed_address!(&voter2_address));
             let voter3_id = sc.users().get_user_id(&managed_address!(&voter3_address));
 
-            assert!(!sc.withdrawable_voters(proposal_id).contains(&voter1_id));
-            assert!(!sc.withdrawable_voters(proposal_id).contains(&voter2_id));
-            assert!(!sc.withdrawable_voters(proposal_id).contains(&voter3_id));
+            assert!(!sc.locked_voters(proposal_id).contains(&voter1_id));
+            assert!(!sc.locked_voters(proposal_id).contains(&voter2_id));
+            assert!(!sc.locked_voters(proposal_id).contains(&voter3_id));
 
-            assert!(sc.withdrawable_votes(proposal_id, &managed_address!(&voter1_address)).is_empty());
-            assert!(sc.withdrawable_votes(proposal_id, &managed_address!(&voter2_address)).is_empty());
-            assert!(sc.withdrawable_votes(proposal_id, &managed_address!(&voter3_address)).is_empty());
+            assert!(sc.locked_votes(proposal_id, voter1_id).is_empty());
+            assert!(sc.locked_votes(proposal_id, voter2_id).is_empty());
+            assert!(sc.locked_votes(proposal_id, voter3_id).is_empty());
         })
         .assert_ok();
 
     // assert that vote tokens are back in the user's wallet
-    setup.blockchain.check_esdt_balance(&voter1_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(5));
-    setup.blockchain.check_esdt_balance(&voter2_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(10));
-    setup.blockchain.check_esdt_balance(&voter3_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(20));
+    setup.blockchain.check_esdt_balance(voter1_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(5));
+    setup.blockchain.check_esdt_balance(voter2_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(10));
+    setup.blockchain.check_esdt_balance(voter3_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(20));
 }
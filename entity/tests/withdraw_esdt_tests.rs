@@ -1,3 +1,4 @@
+use entity::permission::*;
 use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::codec::multi_types::*;
@@ -14,32 +15,34 @@ fn it_withdraws_tokens_used_for_voting() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
             sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
-            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
@@ -47,14 +50,14 @@ fn it_withdraws_tokens_used_for_voting() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.withdraw_endpoint();
         })
         .assert_ok();
 
     setup
         .blockchain
-        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+        .check_esdt_balance(user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
 }
 
 #[test]
@@ -64,6 +67,7 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -74,7 +78,8 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -91,8 +96,8 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert!(!sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal_id));
-            assert!(sc.withdrawable_votes(proposal_id, &managed_address!(&voter_address)).is_empty());
+            assert!(!sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal_id));
+            assert!(sc.locked_votes(proposal_id, sc.users().get_user_id(&managed_address!(&voter_address))).is_empty());
         })
         .assert_ok();
 }
@@ -104,6 +109,7 @@ fn it_reduces_the_guarded_vote_token_amount() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
@@ -114,7 +120,8 @@ fn it_reduces_the_guarded_vote_token_amount() {
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
 
             assert_eq!(
@@ -143,24 +150,26 @@ fn it_does_not_withdraw_tokens_from_proposals_that_are_still_active() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.withdraw_endpoint();
         })
         .assert_ok();
@@ -168,11 +177,11 @@ fn it_does_not_withdraw_tokens_from_proposals_that_are_still_active() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&user_address)).contains(&proposal_id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&user_address))).contains(&proposal_id));
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal_id, &managed_address!(&user_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal_id, sc.users().get_user_id(&managed_address!(&user_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 }
@@ -184,73 +193,79 @@ fn it_withdraws_tokens_from_multiple_proposals() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id1"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id2"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id3"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id4"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
             proposal_id = sc.propose_endpoint(
                 managed_buffer!(b"id5"),
                 managed_buffer!(b"content hash"),
                 managed_buffer!(b"content signature"),
                 ManagedBuffer::new(),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -259,12 +274,12 @@ fn it_withdraws_tokens_from_multiple_proposals() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.withdraw_endpoint();
         })
         .assert_ok();
 
     setup
         .blockchain
-        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+        .check_esdt_balance(user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
 }
@@ -1,3 +1,4 @@
+use entity::permission::*;
 use multiversx_sc::codec::multi_types::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
@@ -18,15 +19,16 @@ fn it_withdraws_tokens_used_for_voting() {
     let user_address = &setup.user_address.clone();
     let mut proposal_id = 0u64;
 
-    setup.configure_gov_token(true); // misconfigure (NFTs should not be locked)
+    setup.configure_gov_token(true);
+    setup.configure_member_voting(); // misconfigure (NFTs should not be locked)
 
-    setup.blockchain.set_nft_balance(&user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
-    setup.blockchain.set_nft_balance(&user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0);
-    setup.blockchain.set_nft_balance(&user_address, ENTITY_GOV_TOKEN_ID, 3, &rust_biguint!(1), &0);
+    setup.blockchain.set_nft_balance(user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
+    setup.blockchain.set_nft_balance(user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0);
+    setup.blockchain.set_nft_balance(user_address, ENTITY_GOV_TOKEN_ID, 3, &rust_biguint!(1), &0);
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), |sc| {
             sc.min_propose_weight().set(BigUint::from(1u64));
 
             proposal_id = sc.propose_endpoint(
@@ -35,22 +37,23 @@ fn it_withdraws_tokens_used_for_voting() {
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), |sc| {
             sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 3, &rust_biguint!(1), |sc| {
-            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 3, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
         })
         .assert_ok();
 
@@ -58,7 +61,7 @@ fn it_withdraws_tokens_used_for_voting() {
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.withdraw_endpoint();
         })
         .assert_ok();
@@ -66,15 +69,15 @@ fn it_withdraws_tokens_used_for_voting() {
     // assert that the NFTs are back in the user's wallet
     setup
         .blockchain
-        .check_nft_balance::<u32>(&user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), Option::None);
+        .check_nft_balance::<u32>(user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), Option::None);
 
     setup
         .blockchain
-        .check_nft_balance::<u32>(&user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), Option::None);
+        .check_nft_balance::<u32>(user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), Option::None);
 
     setup
         .blockchain
-        .check_nft_balance::<u32>(&user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), Option::None);
+        .check_nft_balance::<u32>(user_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), Option::None);
 }
 
 #[test]
@@ -84,6 +87,7 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
 
@@ -98,7 +102,8 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -115,8 +120,8 @@ fn it_clears_the_voters_withdrawable_storage_for_the_proposal() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert!(!sc.withdrawable_proposal_ids(&managed_address!(&voter_address)).contains(&proposal_id));
-            assert!(sc.withdrawable_votes(proposal_id, &managed_address!(&voter_address)).is_empty());
+            assert!(!sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&voter_address))).contains(&proposal_id));
+            assert!(sc.locked_votes(proposal_id, sc.users().get_user_id(&managed_address!(&voter_address))).is_empty());
         })
         .assert_ok();
 }
@@ -128,6 +133,7 @@ fn it_reduces_the_guarded_vote_token_amount() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
     setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), &0);
 
@@ -142,7 +148,8 @@ fn it_reduces_the_guarded_vote_token_amount() {
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
@@ -171,12 +178,13 @@ fn it_does_not_withdraw_tokens_from_proposals_that_are_still_active() {
     let mut proposal_id = 0u64;
 
     setup.configure_gov_token(true);
+    setup.configure_member_voting();
 
-    setup.blockchain.set_nft_balance(&user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(5), &0);
+    setup.blockchain.set_nft_balance(user_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(5), &0);
 
     setup
         .blockchain
-        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), |sc| {
+        .execute_esdt_transfer(user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(1), |sc| {
             sc.min_propose_weight().set(BigUint::from(1u64));
 
             proposal_id = sc.propose_endpoint(
@@ -185,14 +193,15 @@ fn it_does_not_withdraw_tokens_from_proposals_that_are_still_active() {
                 managed_buffer!(b"content signature"),
                 managed_buffer!(b""),
                 POLL_DEFAULT_ID,
-                MultiValueManagedVec::new(),
+                0,
+                roles(&[ROLE_BUILTIN_MEMBER]),
             );
         })
         .assert_ok();
 
     setup
         .blockchain
-        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+        .execute_tx(user_address, &setup.contract, &rust_biguint!(0), |sc| {
             sc.withdraw_endpoint();
         })
         .assert_ok();
@@ -200,11 +209,11 @@ fn it_does_not_withdraw_tokens_from_proposals_that_are_still_active() {
     setup
         .blockchain
         .execute_query(&setup.contract, |sc| {
-            assert!(sc.withdrawable_proposal_ids(&managed_address!(&user_address)).contains(&proposal_id));
+            assert!(sc.locked_votes_proposal_ids(sc.users().get_user_id(&managed_address!(&user_address))).contains(&proposal_id));
 
-            let withdrawable_mapper = sc.withdrawable_votes(proposal_id, &managed_address!(&user_address)).get(1);
-            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.token_identifier);
-            assert_eq!(managed_biguint!(1), withdrawable_mapper.amount);
+            let withdrawable_mapper = sc.locked_votes(proposal_id, sc.users().get_user_id(&managed_address!(&user_address))).get(1);
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), withdrawable_mapper.payment.token_identifier);
+            assert_eq!(managed_biguint!(1), withdrawable_mapper.payment.amount);
         })
         .assert_ok();
 }
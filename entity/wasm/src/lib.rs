@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           73
+// Endpoints:                          155
 // Async Callback:                       1
-// Total number of exported functions:  76
+// Promise callbacks:                    1
+// Total number of exported functions: 159
 
 #![no_std]
 
@@ -25,6 +26,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getVersion => version_view
         getTrustedHostAddress => trusted_host_address
         getGovTokenId => gov_token
+        getGovTokenSupply => gov_token_supply
         getGuardedVoteTokens => guarded_vote_tokens
         isLockingVoteTokens => lock_vote_tokens
         getProposalIdCounter => next_proposal_id
@@ -36,63 +38,145 @@ multiversx_sc_wasm_adapter::endpoints! {
         getLockedVotesProposalIds => locked_votes_proposal_ids
         getLockedVotes => locked_votes
         getQuorum => quorum
+        getQuorumMode => default_quorum_mode
+        getQuorumFallback => quorum_fallback
         getMinVoteWeight => min_vote_weight
         getMinProposeWeight => min_propose_weight
         getVotingPeriodMinutes => voting_period_in_minutes
+        getExecutionDelayMinutes => default_execution_delay_minutes
+        getExecutionWindowMinutes => default_execution_window_minutes
         getRestrictedVoteNonces => restricted_vote_nonces
+        lockContractStage => lock_contract_stage_endpoint
         unlockContractStage => unlock_contract_stage_endpoint
         stageContract => stage_contract_endpoint
         stageContractAndPropose => stage_contract_and_propose_endpoint
+        stageDeployment => stage_deployment_endpoint
+        unstageDeployment => unstage_deployment_endpoint
+        deployContract => deploy_contract_endpoint
         activateContract => activate_contract_endpoint
         getContractStage => get_contract_stage_view
+        getContractStageHash => get_contract_stage_hash_view
+        getChildContracts => get_child_contracts_view
         createRole => create_role_endpoint
         removeRole => remove_role_endpoint
         assignRole => assign_role_endpoint
         unassignRole => unassign_role_endpoint
+        grantRoleAdmin => grant_role_admin_endpoint
+        revokeRoleAdmin => revoke_role_admin_endpoint
+        adminAssignRole => admin_assign_role_endpoint
+        adminUnassignRole => admin_unassign_role_endpoint
+        bindRolePlug => bind_role_plug_endpoint
+        unbindRolePlug => unbind_role_plug_endpoint
+        addRoleParent => add_role_parent_endpoint
+        removeRoleParent => remove_role_parent_endpoint
+        pruneExpiredRoles => prune_expired_roles_endpoint
         createPermission => create_permission_endpoint
         createPolicyWeighted => create_policy_weighted_endpoint
+        createPolicyQuadratic => create_policy_quadratic_endpoint
         createPolicyOne => create_policy_one_endpoint
         createPolicyAll => create_policy_all_endpoint
         createPolicyQuorum => create_policy_quorum_endpoint
         createPolicyMajority => create_policy_majority_endpoint
+        setPolicyExecutionDelay => set_policy_execution_delay_endpoint
+        setPolicyExecutionWindow => set_policy_execution_window_endpoint
+        setPolicyApprovalThreshold => set_policy_approval_threshold_endpoint
+        setPolicyQuorum => set_policy_quorum_endpoint
+        getRoleMemberAmount => get_role_member_amount_view
         getUserRoles => get_user_roles_view
+        getRoleExpiresAt => get_role_expires_at_view
+        getRoleAdmins => get_role_admins_view
+        getRoleMembers => get_role_members_view
+        getMembers => get_members_view
         getPermissions => get_permissions_view
         getPolicies => get_policies_view
         getRoles => roles
-        getRoleMemberAmount => roles_member_amount
+        getRolePlug => role_plug
+        getRoleParents => role_parents
         hasUserPlugVoted => has_user_plug_voted_view
         getPlug => get_plug_view
+        getPlugs => get_plugs_view
         migrateProposalResult => migrate_proposal_result
         legacyProposalWithdrawals => migrate_pending_withdrawals
         changeQuorum => change_quorum_endpoint
+        changeQuorumFallback => change_quorum_fallback_endpoint
         changeMinVoteWeight => change_min_vote_weight_endpoint
         changeMinProposeWeight => change_min_propose_weight_endpoint
         changeVotingPeriodMinutes => change_voting_period_in_minutes_endpoint
+        changeExecutionDelayMinutes => change_execution_delay_in_minutes_endpoint
+        changeExecutionWindowMinutes => change_execution_window_in_minutes_endpoint
         setRestrictedVoteNonces => set_restricted_vote_nonces_endpoint
         setPlug => set_plug_endpoint
+        addPlug => add_plug_endpoint
+        removePlug => remove_plug_endpoint
+        setPlugTotalWeightReporting => set_plug_total_weight_reporting_endpoint
         eject => eject_endpoint
         propose => propose_endpoint
         voteFor => vote_for_endpoint
         voteAgainst => vote_against_endpoint
+        voteAbstain => vote_abstain_endpoint
+        changeVote => change_vote_endpoint
         sign => sign_endpoint
+        queueProposal => queue_proposal_endpoint
         execute => execute_endpoint
         directExecute => direct_execute_endpoint
         cancelProposal => cancel_proposal_endpoint
+        veto => veto_endpoint
         withdraw => withdraw_endpoint
         withdrawAll => withdraw_all_endpoint
+        delegate => delegate_endpoint
+        undelegate => undelegate_endpoint
+        stake => stake_endpoint
+        unstake => unstake_endpoint
+        claimUnstaked => claim_unstaked_endpoint
+        lockEscrow => lock_escrow_endpoint
+        withdrawEscrow => withdraw_escrow_endpoint
+        proposeConviction => propose_conviction_endpoint
+        supportConviction => support_conviction_endpoint
+        withdrawConvictionSupport => withdraw_conviction_support_endpoint
+        queueConviction => queue_conviction_endpoint
+        executeConviction => execute_conviction_endpoint
+        cancelConviction => cancel_conviction_endpoint
+        vetoConviction => veto_conviction_endpoint
+        changeConvictionSettings => change_conviction_settings_endpoint
+        changeUnbondingPeriodMinutes => change_unbonding_period_in_minutes_endpoint
         getProposal => get_proposal_view
         getProposalStatus => get_proposal_status_view
         getProposalVotes => get_proposal_votes_view
+        getVote => get_vote_view
+        getDelegate => get_delegate_view
+        getDelegators => get_delegators_view
+        getDelegatedTokens => get_delegated_tokens_view
+        getStake => get_stake_view
+        getStakeAt => get_stake_at_view
+        getUnbondingStake => get_unbonding_stake_view
+        getUnbondingPeriodMinutes => unbonding_period_minutes
+        getEscrow => get_escrow_view
+        getEscrowPowerAt => get_escrow_power_at_view
+        getConvictionProposal => get_conviction_proposal_view
+        getConvictionStatus => get_conviction_status_view
+        getConviction => get_conviction_view
+        getConvictionThreshold => get_conviction_threshold_view
+        getConvictionMinProposeWeight => get_conviction_min_propose_weight_view
+        getConvictionSupport => get_conviction_support_view
+        getConvictionDecay => conviction_decay
+        getConvictionMaxRatio => conviction_max_ratio
+        getConvictionWeight => conviction_weight
         getProposalSigners => get_proposal_signers_view
         getProposalSignatureRoleCounts => get_proposal_signature_role_counts_view
         getProposalPollResults => get_proposal_poll_results_view
         initGovToken => init_gov_token_endpoint
         changeGovToken => change_gov_token_endpoint
         removeGovToken => remove_gov_token_endpoint
+        changeGovTokenDecimals => change_gov_token_decimals_endpoint
+        getGovTokenDecimals => get_gov_token_decimals_view
+        registerGovToken => register_gov_token_endpoint
+        deregisterGovToken => deregister_gov_token_endpoint
+        getGovTokens => get_gov_tokens_view
         issueGovToken => issue_gov_token_endpoint
         setGovTokenLocalRoles => set_gov_token_local_roles_endpoint
         mint => mint_endpoint
         burn => burn_endpoint
+        plug_weight_callback => plug_weight_callback
     )
 }
 